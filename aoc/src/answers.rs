@@ -0,0 +1,146 @@
+//! Regression harness that solves every puzzle for the real input files and compares the results with the known answers stored in
+//! answers.toml in the workspace root. Days whose input file is not present are skipped.
+
+use crate::days;
+use common::CancelToken;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::thread;
+use toml::Value;
+
+const ANSWERS_FILE: &str = "../answers.toml";
+const INPUTS_DIR: &str = "../inputs";
+
+/// Entry of answers.toml for one day: Parts listed in "slow" take too long for a debug build and are only checked by the ignored test.
+#[derive(Deserialize)]
+struct DayAnswers {
+    part1: Option<Value>,
+    part2: Option<Value>,
+    #[serde(default)]
+    slow: Vec<u8>
+}
+
+struct ExpectedAnswer {
+    day: u8,
+    part: u8,
+    answer: String,
+    slow: bool
+}
+
+fn read_expected_answers() -> Vec<ExpectedAnswer> {
+    let content = read_to_string(ANSWERS_FILE).expect("Could not read answers file!");
+    let days: BTreeMap<String, DayAnswers> = toml::from_str(&content).expect("Could not parse answers file!");
+
+    let mut res = vec!();
+    for (key, day_answers) in days {
+        let day = key.strip_prefix("day").and_then(|d| d.parse().ok()).unwrap_or_else(|| panic!("Invalid key \"{}\" in answers file!", key));
+
+        for (part, value) in [(1, day_answers.part1), (2, day_answers.part2)] {
+            if let Some(value) = value {
+                let answer = match value {
+                    Value::Integer(i) => i.to_string(),
+                    Value::String(s) => s,
+                    _ => panic!("Answer for day {} part {} must be an integer or a string!", day, part)
+                };
+
+                res.push(ExpectedAnswer { day, part, answer, slow: day_answers.slow.contains(&part) });
+            }
+        }
+    }
+
+    res
+}
+
+/// Describes the difference between the expected and the actual answer (line by line for multi-line answers like the CRT image of day 10).
+fn describe_mismatch(day: u8, part: u8, expected: &str, actual: &str) -> String {
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return format!("Day {} - Part {}: expected {}, got {}", day, part, expected, actual);
+    }
+
+    let mut res = format!("Day {} - Part {}: (- expected, + actual)", day, part);
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => res += &format!("\n  {}", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    res += &format!("\n- {}", e);
+                }
+                if let Some(a) = a {
+                    res += &format!("\n+ {}", a);
+                }
+            }
+        }
+    }
+
+    res
+}
+
+/// Solves one part of a puzzle and returns a description of the problem if the answer does not match (or None if it does or if the input
+/// file is not available).
+fn check_answer(expected: &ExpectedAnswer) -> Option<String> {
+    let input_path = Path::new(INPUTS_DIR).join(format!("day{}_input.txt", expected.day));
+    let input = match read_to_string(&input_path) {
+        Ok(input) => input,
+        Err(_) => {
+            eprintln!("Skipping day {} part {}: Input file {} not found.", expected.day, expected.part, input_path.display());
+            return None;
+        }
+    };
+
+    match days::solve(expected.day, expected.part, &input, &CancelToken::new()) {
+        Ok(answer) if answer.to_string() == expected.answer => None,
+        Ok(answer) => Some(describe_mismatch(expected.day, expected.part, &expected.answer, &answer.to_string())),
+        Err(e) => Some(format!("Day {} - Part {}: solver failed: {:#}", expected.day, expected.part, e))
+    }
+}
+
+/// Checks all (slow or not slow) answers in parallel and fails with a list of all mismatches.
+fn check_answers(slow: bool) {
+    let expected_answers = read_expected_answers();
+
+    let mismatches: Vec<String> = thread::scope(|s| {
+        let handles: Vec<_> = expected_answers
+            .iter()
+            .filter(|e| e.slow == slow)
+            .map(|e| s.spawn(move || check_answer(e)))
+            .collect();
+
+        handles.into_iter().filter_map(|h| h.join().expect("Solver thread panicked!")).collect()
+    });
+
+    assert!(mismatches.is_empty(), "{} answer(s) do not match:\n\n{}\n", mismatches.len(), mismatches.join("\n\n"));
+}
+
+#[test]
+fn answers_file_covers_all_puzzles() {
+    let expected_answers = read_expected_answers();
+
+    for day in 1..=days::DAY_COUNT {
+        for part in days::get_parts(day) {
+            assert!(expected_answers.iter().any(|e| e.day == day && e.part == *part), "No answer for day {} part {} in answers file!", day, part);
+        }
+    }
+}
+
+#[test]
+fn real_inputs() {
+    check_answers(false);
+}
+
+/// Run with: cargo test --release -p aoc -- --ignored
+#[test]
+#[ignore]
+fn real_inputs_slow() {
+    check_answers(true);
+}
+
+#[test]
+fn mismatch_description() {
+    assert_eq!(describe_mismatch(1, 2, "45000", "44000"), "Day 1 - Part 2: expected 45000, got 44000");
+    assert_eq!(describe_mismatch(10, 2, "#.\n.#", "#.\n##"), "Day 10 - Part 2: (- expected, + actual)\n  #.\n- .#\n+ ##");
+}
@@ -0,0 +1,223 @@
+use crate::days::{self, DayVisitor};
+use anyhow::{bail, Context, Result};
+use common::{CancelToken, Solution};
+use serde::{Deserialize, Serialize};
+use std::fs::{read_to_string, write};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Settings of a benchmark run: Every stage is run once for warm-up and then repeatedly until the measurement time has passed (but at
+/// least once and at most max_samples times).
+pub struct BenchConfig {
+    pub measurement_time: Duration,
+    pub max_samples: usize
+}
+
+/// Timing statistics of one benchmarked stage of a day (stage is either "parse", "part1" or "part2"). The input is identified by its kind
+/// and a hash of its contents (see input_identity()), so that results are only compared for the same input.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StageResult {
+    pub day: u8,
+    pub stage: String,
+    #[serde(default)]
+    pub input: String,
+    pub samples: usize,
+    pub mean_ms: f64,
+    pub median_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64
+}
+
+/// Contents of a baseline file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BenchReport {
+    pub results: Vec<StageResult>
+}
+
+/// Outcome of comparing the median time of a stage with the baseline.
+#[derive(Debug, PartialEq)]
+pub enum Comparison {
+    Regression { change_percent: f64 },
+    Improvement { change_percent: f64 },
+    NoChange { change_percent: f64 },
+    NotInBaseline,
+    /// The median time of the baseline is zero (e.g. too fast for the timer), so there is no relative change
+    ZeroBaseline
+}
+
+/// Returns a description of the input for identifying it in a baseline: The real input or the seed and size of a generated input,
+/// together with an FNV-1a hash of the contents (which differ between accounts for the real input).
+pub fn input_identity(input: &str, generated: Option<(u64, usize)>) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+    match generated {
+        Some((seed, size)) => format!("generated (seed {}, size {}, hash {:016x})", seed, size, hash),
+        None => format!("real (hash {:016x})", hash)
+    }
+}
+
+impl BenchReport {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<BenchReport> {
+        let content = read_to_string(&path).with_context(|| format!("Could not read baseline file {}!", path.as_ref().display()))?;
+        serde_json::from_str(&content).context("Could not parse baseline file!")
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        write(&path, serde_json::to_string_pretty(self)?).with_context(|| format!("Could not write baseline file {}!", path.as_ref().display()))
+    }
+
+    /// Compares a result with the result of the same day and stage in this report (a change of the median time that is larger than the
+    /// threshold counts as regression or improvement). Fails if the baseline was measured with a different input.
+    pub fn compare(&self, result: &StageResult, threshold_percent: f64) -> Result<Comparison> {
+        let baseline = match self.results.iter().find(|r| r.day == result.day && r.stage == result.stage) {
+            Some(b) => b,
+            None => return Ok(Comparison::NotInBaseline)
+        };
+
+        if baseline.input != result.input {
+            let baseline_input = if baseline.input.is_empty() { "unknown" } else { baseline.input.as_str() };
+            bail!("Baseline of day {} was measured with a different input ({}) than the current one ({})!", result.day, baseline_input,
+                result.input);
+        }
+
+        if baseline.median_ms <= 0.0 {
+            return Ok(Comparison::ZeroBaseline);
+        }
+
+        let change_percent = (result.median_ms - baseline.median_ms) / baseline.median_ms * 100.0;
+        Ok(if change_percent > threshold_percent {
+            Comparison::Regression { change_percent }
+        } else if change_percent < -threshold_percent {
+            Comparison::Improvement { change_percent }
+        } else {
+            Comparison::NoChange { change_percent }
+        })
+    }
+}
+
+/// Runs the given function repeatedly and calculates the timing statistics.
+fn measure<T, F: FnMut() -> Result<T>>(day: u8, stage: &str, config: &BenchConfig, mut f: F) -> Result<StageResult> {
+    // Warm-up run (only used as a sample if it already took longer than the whole measurement time)
+    let start_time = Instant::now();
+    black_box(f()?);
+    let warm_up_time = start_time.elapsed();
+
+    let mut samples = vec!();
+    if warm_up_time >= config.measurement_time {
+        samples.push(warm_up_time);
+    } else {
+        let measurement_start_time = Instant::now();
+        while samples.is_empty() || (samples.len() < config.max_samples && measurement_start_time.elapsed() < config.measurement_time) {
+            let start_time = Instant::now();
+            black_box(f()?);
+            samples.push(start_time.elapsed());
+        }
+    }
+
+    Ok(calc_stage_result(day, stage, &samples))
+}
+
+fn calc_stage_result(day: u8, stage: &str, samples: &[Duration]) -> StageResult {
+    let mut samples_ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+    samples_ms.sort_by(|a, b| a.total_cmp(b));
+
+    let n = samples_ms.len();
+    let median_ms = if n.is_multiple_of(2) { (samples_ms[n / 2 - 1] + samples_ms[n / 2]) / 2.0 } else { samples_ms[n / 2] };
+
+    StageResult {
+        day,
+        stage: stage.into(),
+        input: String::new(),
+        samples: n,
+        mean_ms: samples_ms.iter().sum::<f64>() / n as f64,
+        median_ms,
+        min_ms: samples_ms[0],
+        max_ms: samples_ms[n - 1]
+    }
+}
+
+struct BenchVisitor<'a> {
+    day: u8,
+    input: &'a str,
+    input_id: &'a str,
+    config: &'a BenchConfig
+}
+
+impl DayVisitor for BenchVisitor<'_> {
+    type Output = Vec<StageResult>;
+
+    fn visit<S: Solution>(self) -> Result<Vec<StageResult>> {
+        let mut res = vec!(measure(self.day, "parse", self.config, || Ok(S::parse(self.input)?))?);
+
+        let input = S::parse(self.input)?;
+        let cancel = CancelToken::new();
+        for part in days::get_parts(self.day) {
+            res.push(measure(self.day, &format!("part{}", part), self.config, || days::solve_part::<S>(*part, &input, &cancel))?);
+        }
+
+        for r in &mut res {
+            r.input = self.input_id.into();
+        }
+        Ok(res)
+    }
+}
+
+/// Benchmarks parsing the input and solving each part of the puzzle of the given day (input_id identifies the input in the results).
+pub fn bench_day(day: u8, input: &str, input_id: &str, config: &BenchConfig) -> Result<Vec<StageResult>> {
+    days::visit_day(day, BenchVisitor { day, input, input_id, config })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|ms| Duration::from_millis(*ms)).collect();
+        let res = calc_stage_result(1, "parse", &samples);
+        assert_eq!(res.samples, 4);
+        assert_eq!(res.median_ms, 2.5);
+        assert_eq!(res.mean_ms, 2.5);
+        assert_eq!(res.min_ms, 1.0);
+        assert_eq!(res.max_ms, 4.0);
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let samples = [Duration::from_millis(10)];
+        let baseline = BenchReport { results: vec!(calc_stage_result(12, "part1", &samples)) };
+
+        let slower = calc_stage_result(12, "part1", &[Duration::from_millis(12)]);
+        assert_eq!(baseline.compare(&slower, 10.0).unwrap(), Comparison::Regression { change_percent: 20.0 });
+
+        let faster = calc_stage_result(12, "part1", &[Duration::from_millis(5)]);
+        assert_eq!(baseline.compare(&faster, 10.0).unwrap(), Comparison::Improvement { change_percent: -50.0 });
+
+        let same = calc_stage_result(12, "part1", &[Duration::from_millis(10)]);
+        assert_eq!(baseline.compare(&same, 10.0).unwrap(), Comparison::NoChange { change_percent: 0.0 });
+
+        let other_stage = calc_stage_result(12, "part2", &samples);
+        assert_eq!(baseline.compare(&other_stage, 10.0).unwrap(), Comparison::NotInBaseline);
+
+        let zero_baseline = BenchReport { results: vec!(calc_stage_result(12, "part1", &[Duration::ZERO])) };
+        assert_eq!(zero_baseline.compare(&slower, 10.0).unwrap(), Comparison::ZeroBaseline);
+    }
+
+    #[test]
+    fn compare_different_inputs() {
+        let mut baseline = calc_stage_result(12, "part1", &[Duration::from_millis(10)]);
+        baseline.input = input_identity("abc", None);
+        let baseline = BenchReport { results: vec!(baseline) };
+
+        let mut result = calc_stage_result(12, "part1", &[Duration::from_millis(10)]);
+        result.input = input_identity("abc", None);
+        assert!(baseline.compare(&result, 10.0).is_ok());
+
+        result.input = input_identity("abc", Some((3, 30)));
+        assert!(result.input.starts_with("generated (seed 3, size 30, hash "));
+        let e = baseline.compare(&result, 10.0).unwrap_err();
+        assert!(e.to_string().starts_with("Baseline of day 12 was measured with a different input (real (hash "));
+
+        assert_ne!(input_identity("abc", None), input_identity("abd", None));
+    }
+}
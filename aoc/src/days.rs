@@ -0,0 +1,80 @@
+use anyhow::{bail, Result};
+use common::{logging, Answer, CancelToken, Solution};
+
+pub const DAY_COUNT: u8 = 25;
+
+/// Operation that is executed generically for the solution of one day, see visit_day.
+pub trait DayVisitor {
+    type Output;
+
+    fn visit<S: Solution>(self) -> Result<Self::Output>;
+}
+
+/// Calls the visitor with the solution type of the given day.
+pub fn visit_day<V: DayVisitor>(day: u8, visitor: V) -> Result<V::Output> {
+    match day {
+        1 => visitor.visit::<day1::Day1>(),
+        2 => visitor.visit::<day2::Day2>(),
+        3 => visitor.visit::<day3::Day3>(),
+        4 => visitor.visit::<day4::Day4>(),
+        5 => visitor.visit::<day5::Day5>(),
+        6 => visitor.visit::<day6::Day6>(),
+        7 => visitor.visit::<day7::Day7>(),
+        8 => visitor.visit::<day8::Day8>(),
+        9 => visitor.visit::<day9::Day9>(),
+        10 => visitor.visit::<day10::Day10>(),
+        11 => visitor.visit::<day11::Day11>(),
+        12 => visitor.visit::<day12::Day12>(),
+        13 => visitor.visit::<day13::Day13>(),
+        14 => visitor.visit::<day14::Day14>(),
+        15 => visitor.visit::<day15::Day15>(),
+        16 => visitor.visit::<day16::Day16>(),
+        17 => visitor.visit::<day17::Day17>(),
+        18 => visitor.visit::<day18::Day18>(),
+        19 => visitor.visit::<day19::Day19>(),
+        20 => visitor.visit::<day20::Day20>(),
+        21 => visitor.visit::<day21::Day21>(),
+        22 => visitor.visit::<day22::Day22>(),
+        23 => visitor.visit::<day23::Day23>(),
+        24 => visitor.visit::<day24::Day24>(),
+        25 => visitor.visit::<day25::Day25>(),
+        _ => bail!("Invalid day {} (must be in 1..={})!", day, DAY_COUNT)
+    }
+}
+
+/// Solves one part of the puzzle for an input that has already been parsed (long-running solvers give up once the token is cancelled).
+pub fn solve_part<S: Solution>(part: u8, input: &S::Input, cancel: &CancelToken) -> Result<Answer> {
+    match part {
+        1 => Ok(logging::puzzle_span::<S>("part1").in_scope(|| S::part1_cancellable(input, cancel))?.into()),
+        2 => Ok(logging::puzzle_span::<S>("part2").in_scope(|| S::part2_cancellable(input, cancel))?.into()),
+        _ => bail!("Invalid part {} (must be 1 or 2)!", part)
+    }
+}
+
+struct SolveVisitor<'a> {
+    part: u8,
+    input: &'a str,
+    cancel: &'a CancelToken
+}
+
+impl DayVisitor for SolveVisitor<'_> {
+    type Output = Answer;
+
+    fn visit<S: Solution>(self) -> Result<Answer> {
+        let input = logging::puzzle_span::<S>("parse").in_scope(|| S::parse(self.input))?;
+        solve_part::<S>(self.part, &input, self.cancel)
+    }
+}
+
+/// Parses the input and solves one part of the puzzle of the given day.
+pub fn solve(day: u8, part: u8, input: &str, cancel: &CancelToken) -> Result<Answer> {
+    visit_day(day, SolveVisitor { part, input, cancel })
+}
+
+/// Returns which parts can be solved for the given day (day 25 only has a single puzzle).
+pub fn get_parts(day: u8) -> &'static [u8] {
+    match day {
+        25 => &[1],
+        _ => &[1, 2]
+    }
+}
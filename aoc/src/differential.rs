@@ -0,0 +1,105 @@
+//! Differential tests: The solvers that rely on heuristics (pruning, bounds or cycle detection) are compared to the slow reference
+//! solvers of their days on small generated inputs.
+
+use common::{CancelToken, Solution};
+use day16::Day16;
+use day17::Day17;
+use day19::Day19;
+use std::thread;
+
+/// Input sizes and puzzle parameters of a differential test run (the optimized solvers are the slow ones in a debug build).
+struct Scale {
+    seeds: u64,
+    /// Number of valves for the puzzle without and with the elephant
+    day16_sizes: (usize, usize),
+    /// Number of jets is five times the seed
+    day17_rounds: &'static [u64],
+    day19_blueprints: usize,
+    day19_minutes: &'static [u8]
+}
+
+const QUICK: Scale = Scale {
+    seeds: 4,
+    day16_sizes: (10, 8),
+    day17_rounds: &[1, 2022, 4321],
+    day19_blueprints: 2,
+    day19_minutes: &[20]
+};
+
+const EXTENDED: Scale = Scale {
+    seeds: 10,
+    day16_sizes: (12, 10),
+    day17_rounds: &[1, 2022, 4321, 50000],
+    day19_blueprints: 3,
+    day19_minutes: &[24]
+};
+
+fn check_day16(scale: &Scale, seed: u64) -> Vec<String> {
+    [(scale.day16_sizes.0, 30, false), (scale.day16_sizes.1, 26, true)]
+        .into_iter()
+        .filter_map(|(size, minutes, include_elephant)| {
+            let valves = Day16::parse(&gen::generate(16, seed, size).unwrap()).unwrap();
+            let optimized = day16::calc_max_releasable_pressure(&valves, "AA", minutes, include_elephant, &CancelToken::new()).unwrap();
+            let reference = day16::reference::calc_max_releasable_pressure(&valves, "AA", minutes, include_elephant);
+            (optimized != reference).then(|| format!("Day 16 (seed {}, elephant {}): {} != reference {}", seed, include_elephant,
+                optimized, reference))
+        })
+        .collect()
+}
+
+fn check_day17(scale: &Scale, seed: u64) -> Vec<String> {
+    let push_directions = Day17::parse(&gen::generate(17, seed, 5 * seed as usize).unwrap()).unwrap();
+
+    scale.day17_rounds
+        .iter()
+        .filter_map(|rounds| {
+            let optimized = day17::calc_height_after_rounds(&push_directions, *rounds, &CancelToken::new()).unwrap();
+            let reference = day17::reference::calc_height_after_rounds(&push_directions, *rounds);
+            (optimized != reference).then(|| format!("Day 17 (seed {}, {} rounds): {} != reference {}", seed, rounds, optimized, reference))
+        })
+        .collect()
+}
+
+fn check_day19(scale: &Scale, seed: u64) -> Vec<String> {
+    let blueprints = Day19::parse(&gen::generate(19, seed, scale.day19_blueprints).unwrap()).unwrap();
+
+    blueprints
+        .iter()
+        .enumerate()
+        .flat_map(|(i, bp)| scale.day19_minutes.iter().map(move |minutes| (i, bp, *minutes)))
+        .filter_map(|(i, bp, minutes)| {
+            let optimized = day19::get_max_geode_count(bp, minutes, &CancelToken::new()).unwrap();
+            let reference = day19::reference::get_max_geode_count(bp, minutes);
+            (optimized != reference).then(|| format!("Day 19 (seed {}, blueprint {}, {} minutes): {} != reference {}", seed, i + 1,
+                minutes, optimized, reference))
+        })
+        .collect()
+}
+
+fn check_all(scale: &Scale) {
+    let checks: [fn(&Scale, u64) -> Vec<String>; 3] = [check_day16, check_day17, check_day19];
+
+    let failures: Vec<String> = thread::scope(|s| {
+        let handles: Vec<_> = checks
+            .iter()
+            .flat_map(|check| (1..=scale.seeds).map(move |seed| (check, seed)))
+            .map(|(check, seed)| s.spawn(move || check(scale, seed)))
+            .collect();
+
+        handles.into_iter().flat_map(|h| h.join().expect("Solver thread panicked!")).collect()
+    });
+
+    assert!(failures.is_empty(), "{} answer(s) differ:\n\n{}\n", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn optimized_matches_reference() {
+    check_all(&QUICK);
+}
+
+/// Run with: cargo test --release -p aoc -- --ignored
+#[test]
+#[ignore]
+fn optimized_matches_reference_extended() {
+    check_all(&EXTENDED);
+}
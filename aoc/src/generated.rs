@@ -0,0 +1,60 @@
+//! Checks that the generated inputs of every day can be parsed and solved. The inputs are kept small, so that the debug build of the
+//! solvers can handle them.
+
+use crate::days;
+use common::CancelToken;
+use std::thread;
+
+const SEEDS: [u64; 3] = [1, 2, 3];
+
+/// Sizes at the lower end, where generators must still produce valid inputs (e.g. at least one jet or elf).
+const SMALL_SIZES: [usize; 3] = [0, 1, 2];
+
+/// Size of the generated inputs for each day (index 0 is day 1).
+const SIZES: [usize; 25] = [10, 50, 10, 50, 30, 100, 20, 15, 50, 10, 10, 30, 20, 20, 5, 6, 200, 100, 1, 100, 50, 8, 12, 20, 20];
+
+/// Parts that are too slow for a debug build even for small inputs (the search area of day 15 is fixed to the size of the real input and
+/// day 19 simulates 32 minutes).
+const SLOW_PARTS: [(u8, u8); 2] = [(15, 2), (19, 2)];
+
+fn check_generated_input(day: u8, seed: u64, size: usize) -> Vec<String> {
+    let input = gen::generate(day, seed, size).unwrap();
+
+    days::get_parts(day)
+        .iter()
+        .filter(|part| !SLOW_PARTS.contains(&(day, **part)))
+        .filter_map(|part| match days::solve(day, *part, &input, &CancelToken::new()) {
+            Ok(_) => None,
+            Err(e) => Some(format!("Day {} - Part {} (seed {}, size {}): solver failed: {:#}", day, part, seed, size, e))
+        })
+        .collect()
+}
+
+/// Checks the inputs for the given days, seeds and sizes in parallel.
+fn check_generated_inputs(inputs: Vec<(u8, u64, usize)>) {
+    let failures: Vec<String> = thread::scope(|s| {
+        let handles: Vec<_> = inputs
+            .into_iter()
+            .map(|(day, seed, size)| s.spawn(move || check_generated_input(day, seed, size)))
+            .collect();
+
+        handles.into_iter().flat_map(|h| h.join().expect("Solver thread panicked!")).collect()
+    });
+
+    assert!(failures.is_empty(), "{} part(s) failed:\n\n{}\n", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn generated_inputs() {
+    check_generated_inputs((1..=days::DAY_COUNT)
+        .flat_map(|day| SEEDS.iter().map(move |seed| (day, *seed, SIZES[day as usize - 1])))
+        .collect());
+}
+
+#[test]
+fn small_generated_inputs() {
+    check_generated_inputs((1..=days::DAY_COUNT)
+        .flat_map(|day| SMALL_SIZES.iter().map(move |size| (day, SEEDS[0], *size)))
+        .collect());
+}
+
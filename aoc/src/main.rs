@@ -0,0 +1,440 @@
+use anyhow::{bail, Context, Result};
+use bench::{BenchConfig, BenchReport, Comparison};
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{Answer, CancelToken, ParseError, TimedOut};
+use fetch::{InputProvider, Submitter};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fs::{read_to_string, write};
+use std::io::{stdin, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+mod bench;
+mod days;
+mod memory;
+mod visualize;
+
+#[cfg(test)]
+mod answers;
+#[cfg(test)]
+mod differential;
+#[cfg(test)]
+mod generated;
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solvers of all dayN packages")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Number of threads for solving (only with the parallel feature), defaults to the number of CPUs
+    #[arg(long, global = true)]
+    threads: Option<usize>
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable line per answer
+    Text,
+
+    /// One JSON object per answer and line
+    Json
+}
+
+/// Result of solving one part of a puzzle as it is written in JSON output mode (parts that timed out have the progress of the solver
+/// instead of an answer).
+#[derive(Serialize)]
+struct AnswerRecord {
+    day: u8,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timed_out: Option<String>,
+    elapsed_ms: f64
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves the puzzle of one day (or of all days if no day is given)
+    Run {
+        /// Day to solve (1-25)
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Part to solve (1 or 2), both parts are solved if not given
+        #[arg(long)]
+        part: Option<u8>,
+
+        /// Input file or "-" for stdin (only allowed together with --day), defaults to <inputs-dir>/dayN_input.txt (which is downloaded
+        /// if it does not exist yet and the AOC_SESSION environment variable is set)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Directory containing (or caching downloaded) input files
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+
+        /// Output format of the answers
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Time limit in seconds for each part: Long-running solvers give up after it and the remaining parts are still solved
+        #[arg(long)]
+        timeout: Option<f64>,
+
+        /// Reports the peak heap usage, number of allocations and allocated bytes of parsing and each part instead of the answers (the
+        /// days are profiled one after another, even with the parallel feature)
+        #[arg(long)]
+        profile_mem: bool
+    },
+
+    /// Solves one part of a puzzle and submits the answer (wrong answers and rate limits are recorded in <inputs-dir>/submissions.json)
+    Submit {
+        /// Day to solve (1-25)
+        #[arg(long)]
+        day: u8,
+
+        /// Part to solve (1 or 2)
+        #[arg(long)]
+        part: u8,
+
+        /// Input file, defaults to <inputs-dir>/dayN_input.txt (which is downloaded if it does not exist yet)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Directory containing (or caching downloaded) input files and the submission ledger
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf
+    },
+
+    /// Benchmarks parsing the input and solving each part for one day (or for all days with an input file if no day is given)
+    Bench {
+        /// Day to benchmark (1-25)
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Directory containing (or caching downloaded) input files
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+
+        /// Time in seconds that each stage is measured for
+        #[arg(long, default_value_t = 2.0)]
+        measurement_time: f64,
+
+        /// Maximum number of samples taken for each stage
+        #[arg(long, default_value_t = 100)]
+        max_samples: usize,
+
+        /// Baseline file (JSON) to compare the results with
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Change of the median time in percent that is reported as regression or improvement
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// File (JSON) to save the results to, so that they can be used as baseline for a later run
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+
+        /// Benchmarks a generated input for this seed instead of the real input
+        #[arg(long)]
+        gen_seed: Option<u64>,
+
+        /// Size of the generated input (see gen command), defaults to a size similar to the real input
+        #[arg(long, requires = "gen_seed")]
+        gen_size: Option<usize>
+    },
+
+    /// Steps through the simulation of a grid-based day (14, 17, 22, 23 or 24) frame by frame in the terminal
+    Visualize {
+        /// Day to visualize
+        #[arg(long)]
+        day: u8,
+
+        /// Part to visualize (1 or 2)
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+
+        /// Input file or "-" for stdin, defaults to <inputs-dir>/dayN_input.txt (which is downloaded if it does not exist yet)
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Directory containing (or caching downloaded) input files
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf
+    },
+
+    /// Generates a random input for one day
+    Gen {
+        /// Day to generate the input for (1-25)
+        #[arg(long)]
+        day: u8,
+
+        /// Seed of the random number generator (the same seed and size always yield the same input)
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// Size of the input (e.g. number of lines or side length of a map, depending on the day), defaults to a size similar to the
+        /// real input
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// File to write the input to (instead of stdout)
+        #[arg(long)]
+        output: Option<PathBuf>
+    }
+}
+
+/// Reads the whole puzzle input from the given file or from stdin if the path is "-".
+fn read_puzzle_input(input_path: &Path) -> Result<String> {
+    if input_path == Path::new("-") {
+        let mut input = String::new();
+        stdin().read_to_string(&mut input).context("Could not read input from stdin!")?;
+        Ok(input)
+    } else {
+        read_to_string(input_path).with_context(|| format!("Could not read input file {}!", input_path.display()))
+    }
+}
+
+/// Adds the input file to parse errors so that they point to the right file (inputs from stdin stay anonymous).
+fn attach_input_file(error: anyhow::Error, input_path: &Path) -> anyhow::Error {
+    match error.downcast::<ParseError>() {
+        Ok(e) if input_path != Path::new("-") => e.with_file(input_path).into(),
+        Ok(e) => e.into(),
+        Err(e) => e
+    }
+}
+
+/// Sets the number of threads that the parallel solvers (and the runner) use.
+#[cfg(feature = "parallel")]
+fn set_thread_count(threads: usize) -> Result<()> {
+    rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().context("Could not set up the thread pool!")
+}
+
+#[cfg(not(feature = "parallel"))]
+fn set_thread_count(_threads: usize) -> Result<()> {
+    bail!("A thread count can only be given if the runner was built with the parallel feature!");
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    common::init_logging();
+
+    if let Some(threads) = cli.threads {
+        set_thread_count(threads)?;
+    }
+
+    match cli.command {
+        Command::Run { day, part, input, inputs_dir, format, timeout, profile_mem } => {
+            if input.is_some() && day.is_none() {
+                bail!("An input file can only be given together with --day!");
+            }
+            let timeout = timeout.map(Duration::try_from_secs_f64).transpose().context("Invalid timeout!")?;
+
+            let day_list = match day {
+                Some(d) => vec!(d),
+                None => (1..=days::DAY_COUNT).collect()
+            };
+
+            // All inputs are read (and downloaded if necessary) one after another before solving any day
+            let inputs = day_list
+                .into_iter()
+                .map(|d| {
+                    let input_path = match &input {
+                        Some(p) => p.clone(),
+                        None => InputProvider::from_env(&inputs_dir).input_path(d)?
+                    };
+                    let puzzle_input = read_puzzle_input(&input_path)?;
+                    Ok((d, input_path, puzzle_input))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            if profile_mem {
+                let mut timed_out_count = 0;
+
+                for (d, input_path, puzzle_input) in &inputs {
+                    let parts = match part {
+                        Some(p) => vec!(p),
+                        None => days::get_parts(*d).to_vec()
+                    };
+
+                    let day_stats = memory::profile_day(*d, &parts, puzzle_input, timeout).map_err(|e| attach_input_file(e, input_path))?;
+                    for stats in day_stats {
+                        timed_out_count += stats.timed_out.is_some() as usize;
+
+                        match format {
+                            OutputFormat::Text => {
+                                let timed_out = stats.timed_out.as_ref().map(|p| format!(" - timed out ({})", p)).unwrap_or_default();
+                                println!("Day {} - {:<5}: peak heap {}, {} allocations ({} allocated){}", stats.day, stats.stage,
+                                    memory::format_bytes(stats.peak_bytes as u64), stats.allocations,
+                                    memory::format_bytes(stats.allocated_bytes), timed_out)
+                            },
+                            OutputFormat::Json => println!("{}", serde_json::to_string(&stats)?)
+                        }
+                    }
+                }
+
+                if timed_out_count > 0 {
+                    bail!("{} part(s) timed out!", timed_out_count);
+                }
+                return Ok(());
+            }
+
+            let solve_day = |(d, input_path, puzzle_input): &(u8, PathBuf, String)| -> Result<Vec<AnswerRecord>> {
+                let part_list = match part {
+                    Some(p) => vec!(p),
+                    None => days::get_parts(*d).to_vec()
+                };
+
+                part_list
+                    .into_iter()
+                    .map(|p| {
+                        let cancel = timeout.map(CancelToken::with_timeout).unwrap_or_default();
+                        let start_time = Instant::now();
+                        let result = days::solve(*d, p, puzzle_input, &cancel);
+                        let elapsed_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+
+                        // A timed out part is reported with the progress of the solver, all other errors end the run
+                        let (answer, timed_out) = match result.map_err(|e| e.downcast::<TimedOut>()) {
+                            Ok(answer) => (Some(answer), None),
+                            Err(Ok(e)) => (None, Some(e.progress)),
+                            Err(Err(e)) => return Err(attach_input_file(e, input_path))
+                        };
+                        Ok(AnswerRecord { day: *d, part: p, answer, timed_out, elapsed_ms })
+                    })
+                    .collect()
+            };
+
+            // With the parallel feature, the days are solved at the same time (the answers are still printed in the order of the days)
+            #[cfg(feature = "parallel")]
+            let results = inputs.par_iter().map(solve_day).collect::<Vec<_>>();
+            #[cfg(not(feature = "parallel"))]
+            let results = inputs.iter().map(solve_day);
+
+            let mut timed_out_count = 0;
+
+            for result in results {
+                for record in result? {
+                    timed_out_count += record.timed_out.is_some() as usize;
+
+                    match format {
+                        OutputFormat::Text => match &record.answer {
+                            Some(answer @ Answer::Grid(_)) => println!("Day {} - Part {}:\n{}", record.day, record.part, answer),
+                            Some(answer) => println!("Day {} - Part {}: {}", record.day, record.part, answer),
+                            None => println!("Day {} - Part {}: Timed out after {:.0} ms ({})", record.day, record.part, record.elapsed_ms,
+                                record.timed_out.as_deref().unwrap_or_default())
+                        },
+                        OutputFormat::Json => println!("{}", serde_json::to_string(&record)?)
+                    }
+                }
+            }
+
+            if timed_out_count > 0 {
+                bail!("{} part(s) timed out!", timed_out_count);
+            }
+        }
+        Command::Submit { day, part, input, inputs_dir } => {
+            let input_path = match input {
+                Some(p) => p,
+                None => InputProvider::from_env(&inputs_dir).input_path(day)?
+            };
+            let puzzle_input = read_puzzle_input(&input_path)?;
+            let answer = days::solve(day, part, &puzzle_input, &CancelToken::new()).map_err(|e| attach_input_file(e, &input_path))?;
+            println!("Day {} - Part {}: {}", day, part, answer);
+
+            let result = Submitter::from_env(inputs_dir.join("submissions.json")).submit(day, part, &answer)?;
+            println!("{}", result);
+        }
+        Command::Bench { day, inputs_dir, measurement_time, max_samples, baseline, threshold, save_baseline, gen_seed, gen_size } => {
+            let config = BenchConfig { measurement_time: Duration::from_secs_f64(measurement_time), max_samples };
+            let baseline = baseline.map(BenchReport::load).transpose()?;
+            let mut report = BenchReport::default();
+            let mut regression_count = 0;
+
+            let day_list = match day {
+                Some(d) => vec!(d),
+                None => (1..=days::DAY_COUNT).collect()
+            };
+
+            for d in day_list {
+                let (input_path, puzzle_input, generated) = match gen_seed {
+                    Some(seed) => {
+                        // Generated inputs are not stored in a file, so parse errors are reported without one (like for stdin)
+                        let size = gen_size.unwrap_or_else(|| gen::default_size(d));
+                        (PathBuf::from("-"), gen::generate(d, seed, size)?, Some((seed, size)))
+                    },
+                    None => {
+                        let provider = InputProvider::from_env(&inputs_dir);
+                        if day.is_none() && !provider.is_cached(d) && !provider.has_session() {
+                            println!("Day {}: Skipped (input file {} not found)", d, provider.cached_path(d).display());
+                            continue;
+                        }
+
+                        let input_path = provider.input_path(d)?;
+                        let puzzle_input = read_puzzle_input(&input_path)?;
+                        (input_path, puzzle_input, None)
+                    }
+                };
+
+                let input_id = bench::input_identity(&puzzle_input, generated);
+                for result in bench::bench_day(d, &puzzle_input, &input_id, &config).map_err(|e| attach_input_file(e, &input_path))? {
+                    let comparison = match &baseline {
+                        Some(b) => match b.compare(&result, threshold)? {
+                            Comparison::Regression { change_percent } => {
+                                regression_count += 1;
+                                format!(" => {:+.1} % REGRESSION", change_percent)
+                            },
+                            Comparison::Improvement { change_percent } => format!(" => {:+.1} % improvement", change_percent),
+                            Comparison::NoChange { change_percent } => format!(" => {:+.1} % (no change)", change_percent),
+                            Comparison::NotInBaseline => " => not in baseline".into(),
+                            Comparison::ZeroBaseline => " => not compared (median of baseline is zero)".into()
+                        },
+                        None => String::new()
+                    };
+
+                    println!("Day {} - {:<5}: median {:.3} ms (mean {:.3} ms, min {:.3} ms, max {:.3} ms, {} samples){}",
+                        d, result.stage, result.median_ms, result.mean_ms, result.min_ms, result.max_ms, result.samples, comparison);
+                    report.results.push(result);
+                }
+            }
+
+            if baseline.is_some() {
+                println!("{} regression(s) compared to baseline.", regression_count);
+            }
+
+            if let Some(path) = save_baseline {
+                report.save(path)?;
+            }
+
+            // Regressions fail the run (e.g. for checking them in CI)
+            if regression_count > 0 {
+                bail!("{} regression(s) exceed the threshold of {} %!", regression_count, threshold);
+            }
+        }
+        Command::Visualize { day, part, input, inputs_dir } => {
+            let input_path = match input {
+                Some(p) => p,
+                None => InputProvider::from_env(&inputs_dir).input_path(day)?
+            };
+            let puzzle_input = read_puzzle_input(&input_path)?;
+            visualize::visualize_day(day, part, &puzzle_input).map_err(|e| attach_input_file(e, &input_path))?;
+        }
+        Command::Gen { day, seed, size, output } => {
+            let input = gen::generate(day, seed, size.unwrap_or_else(|| gen::default_size(day)))?;
+
+            match output {
+                Some(path) => write(&path, input).with_context(|| format!("Could not write {}!", path.display()))?,
+                None => print!("{}", input)
+            }
+        }
+    }
+
+    Ok(())
+}
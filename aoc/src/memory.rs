@@ -0,0 +1,232 @@
+//! Memory profiling for the --profile-mem option of the runner: The runner uses a global allocator that counts the allocations while a
+//! stage is measured, so that the peak heap usage, the number of allocations and the allocated bytes can be reported for parsing the
+//! input and each part of a day.
+
+use crate::days::{self, DayVisitor};
+use anyhow::Result;
+use common::{CancelToken, Solution, TimedOut};
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicIsize, AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Number of running measurements, the allocations are only counted while this is not zero (so the other commands like bench are not
+/// slowed down by the counting)
+static ACTIVE_MEASUREMENTS: AtomicUsize = AtomicUsize::new(0);
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+/// Bytes allocated minus bytes freed while counting (negative if more memory was freed than allocated, e.g. the input of a stage)
+static CURRENT_BYTES: AtomicIsize = AtomicIsize::new(0);
+static PEAK_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+/// Allocator that forwards to the system allocator and counts the allocations during measurements (a reallocation counts as an
+/// allocation of the new size).
+pub struct CountingAllocator;
+
+fn is_counting() -> bool {
+    ACTIVE_MEASUREMENTS.load(Ordering::Relaxed) > 0
+}
+
+fn record_allocation(size: usize) {
+    if is_counting() {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let current = CURRENT_BYTES.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+fn record_deallocation(size: usize) {
+    if is_counting() {
+        CURRENT_BYTES.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Memory statistics of one stage of a day (stage is either "parse", "part1" or "part2"). The peak is the largest amount of heap memory
+/// that was in use at the same time on top of what was already allocated before the stage (e.g. the parsed input for the parts). Parts
+/// that timed out have the progress of the solver (the statistics are counted until the solver gave up).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MemoryStats {
+    pub day: u8,
+    pub stage: String,
+    pub peak_bytes: usize,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timed_out: Option<String>
+}
+
+/// Enables counting the allocations while it exists (also if the measured function panics).
+struct CountingGuard;
+
+impl CountingGuard {
+    fn start() -> CountingGuard {
+        ACTIVE_MEASUREMENTS.fetch_add(1, Ordering::Relaxed);
+        CountingGuard
+    }
+}
+
+impl Drop for CountingGuard {
+    fn drop(&mut self) {
+        ACTIVE_MEASUREMENTS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs the given function and counts its allocations (allocations of other threads at the same time are counted as well, so only one
+/// stage should run at a time). The result of the function is returned together with the statistics, also if it failed.
+fn measure<T, F: FnOnce() -> Result<T>>(day: u8, stage: &str, f: F) -> (Result<T>, MemoryStats) {
+    let guard = CountingGuard::start();
+    let start_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start_allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(start_bytes, Ordering::Relaxed);
+
+    let res = f();
+
+    let stats = MemoryStats {
+        day,
+        stage: stage.into(),
+        peak_bytes: (PEAK_BYTES.load(Ordering::Relaxed) - start_bytes).max(0) as usize,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - start_allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - start_allocated_bytes,
+        timed_out: None
+    };
+    drop(guard);
+
+    (res, stats)
+}
+
+/// Formats a number of bytes with a binary prefix (e.g. "1.5 MiB").
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 { format!("{} B", bytes) } else { format!("{:.1} {}", value, UNITS[unit]) }
+}
+
+struct ProfileVisitor<'a> {
+    day: u8,
+    parts: &'a [u8],
+    input: &'a str,
+    timeout: Option<Duration>
+}
+
+impl DayVisitor for ProfileVisitor<'_> {
+    type Output = Vec<MemoryStats>;
+
+    fn visit<S: Solution>(self) -> Result<Vec<MemoryStats>> {
+        let (input, parse_stats) = measure(self.day, "parse", || Ok(S::parse(self.input)?));
+        let input = input?;
+        let mut res = vec!(parse_stats);
+
+        for part in self.parts {
+            let cancel = self.timeout.map(CancelToken::with_timeout).unwrap_or_default();
+            let (answer, mut stats) = measure(self.day, &format!("part{}", part), || days::solve_part::<S>(*part, &input, &cancel));
+
+            // A timed out part is reported with the progress of the solver, all other errors end the run
+            if let Err(e) = answer {
+                stats.timed_out = Some(e.downcast::<TimedOut>()?.progress);
+            }
+            res.push(stats);
+        }
+
+        Ok(res)
+    }
+}
+
+/// Parses the input and solves the given parts of a day, measuring the memory usage of each stage.
+pub fn profile_day(day: u8, parts: &[u8], input: &str, timeout: Option<Duration>) -> Result<Vec<MemoryStats>> {
+    days::visit_day(day, ProfileVisitor { day, parts, input, timeout })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, MutexGuard};
+
+    /// Held by the tests that measure, since a measurement on another test thread would count the allocations of the checks below.
+    static MEASURING: Mutex<()> = Mutex::new(());
+
+    fn lock_measuring() -> MutexGuard<'static, ()> {
+        // A failed test poisons the lock, which must not fail the other tests as well
+        MEASURING.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[test]
+    fn counting() {
+        let _lock = lock_measuring();
+
+        // Other tests run at the same time and may free memory during the measurement, so the peak is not checked here
+        let (v, stats) = measure(1, "part1", || Ok(vec![0u8; 1 << 20]));
+        assert_eq!(v.unwrap().len(), 1 << 20);
+        assert!(stats.allocations >= 1);
+        assert!(stats.allocated_bytes >= 1 << 20);
+
+        // Allocations outside of measurements are not counted
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        assert_eq!(std::hint::black_box(vec![0u8; 1024]).len(), 1024);
+        assert_eq!(ALLOCATIONS.load(Ordering::Relaxed), allocations);
+
+        let input = std::fs::read_to_string("../inputs/day1_example.txt").unwrap();
+        let stats = profile_day(1, &[1, 2], &input, None).unwrap();
+        assert_eq!(stats.iter().map(|s| s.stage.as_str()).collect::<Vec<_>>(), vec!("parse", "part1", "part2"));
+        assert!(stats[0].allocations >= 1);
+        assert!(stats.iter().all(|s| s.timed_out.is_none()));
+    }
+
+    #[test]
+    fn timed_out_part() {
+        let _lock = lock_measuring();
+        let input = std::fs::read_to_string("../inputs/day16_example.txt").unwrap();
+        let stats = profile_day(16, &[1], &input, Some(Duration::ZERO)).unwrap();
+        assert_eq!(stats.len(), 2);
+        assert!(stats[1].timed_out.is_some());
+    }
+
+    #[test]
+    fn byte_formatting() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+}
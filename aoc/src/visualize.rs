@@ -0,0 +1,285 @@
+//! Terminal visualizer that steps through the simulations of the grid-based days frame by frame (see common::Visualization), with
+//! controls to play, pause, step and seek.
+
+use anyhow::{bail, Context, Result};
+use common::{Solution, Visualization};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::io::{stdout, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Every CHECKPOINT_INTERVAL-th frame is kept as a snapshot, so seeking backwards only simulates the frames after the closest snapshot.
+const CHECKPOINT_INTERVAL: usize = 100;
+
+/// Number of frames skipped with page up/down.
+const SEEK_FRAMES: usize = 100;
+
+/// Frames per second that can be chosen for playing.
+const SPEEDS: [u32; 9] = [1, 2, 5, 10, 20, 50, 100, 200, 500];
+
+const HELP: &str = "Space: play/pause | Left/Right: step | PgUp/PgDn: seek 100 frames | Home: first frame | +/-: speed | WASD: scroll \
+    | Q: quit";
+
+/// Current frame of a simulation, where frame 0 is the initial state. The frames are simulated on demand.
+pub struct Player<V> {
+    current: V,
+    frame: usize,
+    checkpoints: Vec<V>,
+    /// Known once the end of the simulation has been reached
+    last_frame: Option<usize>
+}
+
+impl<V: Visualization> Player<V> {
+    pub fn new(visualization: V) -> Self {
+        Player { checkpoints: vec!(visualization.clone()), current: visualization, frame: 0, last_frame: None }
+    }
+
+    pub fn current(&self) -> &V {
+        &self.current
+    }
+
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    pub fn last_frame(&self) -> Option<usize> {
+        self.last_frame
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.last_frame == Some(self.frame)
+    }
+
+    /// Seeks to the given frame (or to the last frame if the simulation ends before).
+    pub fn seek(&mut self, frame: usize) {
+        if frame < self.frame {
+            let checkpoint = frame / CHECKPOINT_INTERVAL;
+            self.current = self.checkpoints[checkpoint].clone();
+            self.frame = checkpoint * CHECKPOINT_INTERVAL;
+        }
+
+        while self.frame < frame && !self.is_at_end() {
+            if self.current.step() {
+                self.frame += 1;
+                if self.frame == self.checkpoints.len() * CHECKPOINT_INTERVAL {
+                    self.checkpoints.push(self.current.clone());
+                }
+            } else {
+                self.last_frame = Some(self.frame);
+            }
+        }
+    }
+}
+
+/// Switches the terminal to the alternate screen in raw mode and restores it when dropped (also if the simulation panics).
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<TerminalGuard> {
+        terminal::enable_raw_mode().context("Could not enable raw mode of the terminal!")?;
+        let guard = TerminalGuard;
+        execute!(stdout(), EnterAlternateScreen, Hide)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Playback state of the user interface.
+struct Ui {
+    title: String,
+    playing: bool,
+    speed_idx: usize,
+    /// Number of columns and rows of the frame that are scrolled out of view
+    scroll: (usize, usize)
+}
+
+impl Ui {
+    fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / SPEEDS[self.speed_idx]
+    }
+
+    fn draw<V: Visualization>(&self, out: &mut impl Write, player: &Player<V>) -> Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+
+        let last_frame = player.last_frame().map(|f| f.to_string()).unwrap_or("?".into());
+        let state = if self.playing { format!("playing at {} fps", SPEEDS[self.speed_idx]) } else { "paused".into() };
+        let status = format!("{} | Frame {} of {} | {} | {}", self.title, player.frame(), last_frame, player.current().status(), state);
+
+        // Status line at the top, help at the bottom and as much of the frame as fits in between
+        let rendered = player.current().render();
+        let frame_lines = rendered.lines().skip(self.scroll.1).take(height.saturating_sub(2));
+        let lines = [status.as_str()].into_iter().chain(frame_lines);
+
+        queue!(out, MoveTo(0, 0))?;
+        for (y, line) in lines.enumerate() {
+            let visible: String = line.chars().skip(if y == 0 { 0 } else { self.scroll.0 }).take(width).collect();
+            queue!(out, MoveTo(0, y as u16), Print(visible), Clear(ClearType::UntilNewLine))?;
+        }
+        queue!(out, Clear(ClearType::FromCursorDown))?;
+        queue!(out, MoveTo(0, height.saturating_sub(1) as u16), Print(HELP.chars().take(width).collect::<String>()))?;
+        out.flush()?;
+
+        Ok(())
+    }
+
+    /// Handles key presses and plays the frames until the user quits.
+    fn run<V: Visualization>(&mut self, player: &mut Player<V>) -> Result<()> {
+        let mut out = stdout();
+        let mut next_frame_time = Instant::now();
+
+        loop {
+            self.draw(&mut out, player)?;
+
+            let timeout = if self.playing { next_frame_time.saturating_duration_since(Instant::now()) } else { Duration::from_secs(60) };
+            if !event::poll(timeout)? {
+                if self.playing {
+                    player.seek(player.frame() + 1);
+                    self.playing = !player.is_at_end();
+
+                    // Frames that take longer to simulate than the interval slow down playing instead of being skipped
+                    next_frame_time = (next_frame_time + self.frame_interval()).max(Instant::now());
+                }
+                continue;
+            }
+
+            // Other events (e.g. resizing the terminal) just redraw the frame
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                _ => continue
+            };
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char(' ') => {
+                    self.playing = !self.playing && !player.is_at_end();
+                    next_frame_time = Instant::now() + self.frame_interval();
+                },
+                KeyCode::Right => player.seek(player.frame() + 1),
+                KeyCode::Left => player.seek(player.frame().saturating_sub(1)),
+                KeyCode::PageDown => player.seek(player.frame() + SEEK_FRAMES),
+                KeyCode::PageUp => player.seek(player.frame().saturating_sub(SEEK_FRAMES)),
+                KeyCode::Home => player.seek(0),
+                KeyCode::Char('+') => self.speed_idx = (self.speed_idx + 1).min(SPEEDS.len() - 1),
+                KeyCode::Char('-') => self.speed_idx = self.speed_idx.saturating_sub(1),
+                KeyCode::Char('w') => self.scroll.1 = self.scroll.1.saturating_sub(1),
+                KeyCode::Char('s') => self.scroll.1 += 1,
+                KeyCode::Char('a') => self.scroll.0 = self.scroll.0.saturating_sub(1),
+                KeyCode::Char('d') => self.scroll.0 += 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn play<V: Visualization>(title: String, visualization: V) -> Result<()> {
+    if !stdout().is_terminal() {
+        bail!("The visualizer needs a terminal!");
+    }
+
+    let mut player = Player::new(visualization);
+    let _guard = TerminalGuard::enter()?;
+    Ui { title, playing: false, speed_idx: 3, scroll: (0, 0) }.run(&mut player)
+}
+
+/// Parses the input and steps through the simulation of the given part in the terminal (only the grid-based days have a visualization).
+pub fn visualize_day(day: u8, part: u8, input: &str) -> Result<()> {
+    if part != 1 && part != 2 {
+        bail!("Invalid part {} (must be 1 or 2)!", part);
+    }
+    let title = format!("Day {} - Part {}", day, part);
+
+    match day {
+        14 => play(title, day14::visualization::SandVisualization::new(&day14::Day14::parse(input)?, part == 2)),
+        17 => {
+            let push_directions = day17::Day17::parse(input)?;
+            play(title, day17::visualization::RockVisualization::new(&push_directions, if part == 1 { 2022 } else { 1000000000000 }))
+        },
+        22 => {
+            let (board, moves) = day22::Day22::parse(input)?;
+            if part == 2 {
+                day22::check_cube_layout(&board)?;
+            }
+            play(title, day22::visualization::PathVisualization::new(&board, &moves, part == 2))
+        },
+        23 => {
+            let field = day23::Day23::parse(input)?;
+            play(title, day23::visualization::ElfVisualization::new(&field, if part == 1 { Some(10) } else { None }))
+        },
+        24 => {
+            let valley = day24::Day24::parse(input)?;
+            play(title, day24::visualization::ExpeditionVisualization::new(&valley, part == 2).context("No way to the end found!")?)
+        },
+        _ => bail!("There is no visualization for day {} (only for days 14, 17, 22, 23 and 24)!", day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to a limit, rendering the current number.
+    #[derive(Clone)]
+    struct Counter {
+        value: usize,
+        limit: usize
+    }
+
+    impl Visualization for Counter {
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn render(&self) -> String {
+            self.value.to_string()
+        }
+
+        fn status(&self) -> String {
+            format!("Value {}", self.value)
+        }
+    }
+
+    #[test]
+    fn seeking() {
+        let mut player = Player::new(Counter { value: 0, limit: 250 });
+        assert_eq!(player.current().render(), "0");
+
+        player.seek(230);
+        assert_eq!((player.frame(), player.current().value, player.last_frame()), (230, 230, None));
+        assert_eq!(player.checkpoints.len(), 3);
+
+        // Seeking backwards starts at the closest snapshot
+        player.seek(150);
+        assert_eq!((player.frame(), player.current().value), (150, 150));
+        player.seek(0);
+        assert_eq!((player.frame(), player.current().value), (0, 0));
+
+        // Seeking beyond the end stops at the last frame
+        player.seek(1000);
+        assert_eq!((player.frame(), player.current().value, player.last_frame()), (250, 250, Some(250)));
+        assert!(player.is_at_end());
+        player.seek(249);
+        assert!(!player.is_at_end());
+        assert_eq!(player.current().status(), "Value 249");
+    }
+
+    #[test]
+    fn unsupported_day() {
+        let err = visualize_day(1, 1, "").unwrap_err();
+        assert_eq!(err.to_string(), "There is no visualization for day 1 (only for days 14, 17, 22, 23 and 24)!");
+        assert_eq!(visualize_day(14, 3, "").unwrap_err().to_string(), "Invalid part 3 (must be 1 or 2)!");
+    }
+}
@@ -0,0 +1,60 @@
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// Answer to one part of a puzzle: Most puzzles have a number as the answer, some have a string (e.g. day 5 and day 25) and day 10 has an
+/// image drawn on the CRT display (stored as lines of characters).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Grid(Vec<String>)
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(lines) => write!(f, "{}", lines.join("\n"))
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from("2=-1=0").to_string(), "2=-1=0");
+        assert_eq!(Answer::Grid(vec!("#.".into(), ".#".into())).to_string(), "#.\n.#");
+    }
+}
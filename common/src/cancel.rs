@@ -0,0 +1,82 @@
+//! Cooperative cancellation of long-running solvers: The solvers check a token regularly and give up with a TimedOut error (describing
+//! how far they got) once it has been cancelled or its deadline has passed.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Token that is passed into long-running solvers. Clones share the cancellation state, so a token can be cancelled from another thread.
+/// The default token is never cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that is cancelled automatically once the given time has passed.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        CancelToken { cancelled: Arc::default(), deadline: Instant::now().checked_add(timeout) }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    /// Returns a TimedOut error with the progress description if the token has been cancelled (the description is only built then).
+    pub fn check<F: FnOnce() -> String>(&self, progress: F) -> Result<(), TimedOut> {
+        if self.is_cancelled() {
+            Err(TimedOut { progress: progress() })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Error of a solver that has been cancelled before finding the answer, with a description of the partial progress.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedOut {
+    pub progress: String
+}
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Timed out ({})!", self.progress)
+    }
+}
+
+impl Error for TimedOut {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancellation() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert!(token.check(|| unreachable!()).is_ok());
+
+        clone.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(token.check(|| "round 3".into()), Err(TimedOut { progress: "round 3".into() }));
+        assert_eq!(TimedOut { progress: "round 3".into() }.to_string(), "Timed out (round 3)!");
+    }
+
+    #[test]
+    fn deadline() {
+        assert!(CancelToken::with_timeout(Duration::ZERO).is_cancelled());
+        assert!(!CancelToken::with_timeout(Duration::from_secs(3600)).is_cancelled());
+        assert!(!CancelToken::with_timeout(Duration::MAX).is_cancelled());
+    }
+}
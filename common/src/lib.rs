@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::io::BufRead;
+use std::path::Path;
+
+mod answer;
+mod cancel;
+pub mod logging;
+mod parse_error;
+mod visualization;
+
+pub use answer::Answer;
+pub use cancel::{CancelToken, TimedOut};
+pub use logging::init_logging;
+pub use parse_error::ParseError;
+pub use visualization::Visualization;
+
+/// Interface implemented by the solution of every day: The input is parsed once and then both parts of the puzzle are solved based on it
+/// (parts of the puzzle that modify the input work on a copy).
+pub trait Solution {
+    type Input;
+    type Answer1: Display + Into<Answer>;
+    type Answer2: Display + Into<Answer>;
+
+    /// Parses the puzzle input given as a string (e.g. the contents of the input file).
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Solves the first part, but gives up with a TimedOut error once the token is cancelled. Only long-running solvers check the token,
+    /// all others just call part1.
+    fn part1_cancellable(input: &Self::Input, _cancel: &CancelToken) -> Result<Self::Answer1> {
+        Self::part1(input)
+    }
+
+    /// Solves the second part, but gives up with a TimedOut error once the token is cancelled (see part1_cancellable).
+    fn part2_cancellable(input: &Self::Input, _cancel: &CancelToken) -> Result<Self::Answer2> {
+        Self::part2(input)
+    }
+
+    /// Solves the first part inside a span for the day and part, so that its diagnostics can be told apart (see logging).
+    fn solve_part1(input: &Self::Input) -> Result<Self::Answer1> {
+        logging::puzzle_span::<Self>("part1").in_scope(|| Self::part1(input))
+    }
+
+    /// Solves the second part inside a span for the day and part, so that its diagnostics can be told apart (see logging).
+    fn solve_part2(input: &Self::Input) -> Result<Self::Answer2> {
+        logging::puzzle_span::<Self>("part2").in_scope(|| Self::part2(input))
+    }
+
+    /// Reads the whole puzzle input from a reader (e.g. stdin) and parses it.
+    fn read_input_from<R: BufRead>(mut reader: R) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input).context("Could not read input!")?;
+        Ok(logging::puzzle_span::<Self>("parse").in_scope(|| Self::parse(&input))?)
+    }
+
+    /// Reads the puzzle input from the given file and parses it.
+    fn read_input<P: AsRef<Path>>(input_path: P) -> Result<Self::Input> {
+        let input = read_to_string(&input_path).context("Could not read input file!")?;
+        Ok(logging::puzzle_span::<Self>("parse").in_scope(|| Self::parse(&input)).map_err(|e| e.with_file(input_path))?)
+    }
+}
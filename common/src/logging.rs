@@ -0,0 +1,31 @@
+//! Diagnostics of the solvers (e.g. the number of tracked states or detected cycles) are emitted as tracing events. They are off by
+//! default and can be enabled with filter directives in the AOC_LOG environment variable, e.g. `AOC_LOG=debug` or `AOC_LOG=day17=trace`.
+
+use std::any::type_name;
+use std::io::{stderr, IsTerminal};
+use tracing::level_filters::LevelFilter;
+use tracing::{info_span, Span};
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable containing the filter directives for the diagnostics.
+pub const LOG_ENV_VAR: &str = "AOC_LOG";
+
+const PUZZLE_SPAN_TARGET: &str = "puzzle";
+
+/// Installs a subscriber that writes the enabled events to stderr (so that they are not mixed with the answers on stdout). Does nothing
+/// if a subscriber is already installed.
+pub fn init_logging() {
+    // The puzzle spans are always enabled, so that they show up as context of the events of a day (e.g. with AOC_LOG=day17=debug)
+    let filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::OFF.into())
+        .with_env_var(LOG_ENV_VAR)
+        .from_env_lossy()
+        .add_directive(format!("{}=info", PUZZLE_SPAN_TARGET).parse().unwrap());
+    let _ = tracing_subscriber::fmt().with_env_filter(filter).with_writer(stderr).with_ansi(stderr().is_terminal()).try_init();
+}
+
+/// Returns a span for one stage ("parse", "part1" or "part2") of the puzzle solved by S, which is named after the package of the day.
+pub fn puzzle_span<S: ?Sized>(stage: &'static str) -> Span {
+    let day = type_name::<S>().split("::").next().unwrap_or_default();
+    info_span!(target: PUZZLE_SPAN_TARGET, "puzzle", day, stage)
+}
@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// Error while parsing the puzzle input that points to the problematic position in the input (and is displayed like a compiler diagnostic).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String
+}
+
+impl ParseError {
+    /// Creates an error for the given line of the input. The line and column indices are zero-based (as returned by enumerate()), but
+    /// are stored and displayed one-based.
+    pub fn new<S: Into<String>>(line_idx: usize, col_idx: usize, text: &str, message: S) -> ParseError {
+        ParseError {
+            file: None,
+            line: line_idx + 1,
+            column: col_idx + 1,
+            text: text.into(),
+            message: message.into()
+        }
+    }
+
+    /// Creates an error that refers to a whole line of the input.
+    pub fn in_line<S: Into<String>>(line_idx: usize, text: &str, message: S) -> ParseError {
+        ParseError::new(line_idx, 0, text, message)
+    }
+
+    /// Creates an error for problems that only become apparent at the end of the input (e.g. missing lines).
+    pub fn at_end<S: Into<String>>(input: &str, message: S) -> ParseError {
+        let line_idx = input.lines().count().saturating_sub(1);
+        let text = input.lines().last().unwrap_or("");
+        ParseError::new(line_idx, text.len(), text, message)
+    }
+
+    /// Creates an error for the given byte offset into the input (which may consist of several lines).
+    pub fn at_offset<S: Into<String>>(input: &str, offset: usize, message: S) -> ParseError {
+        let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..].find('\n').map(|i| offset + i).unwrap_or(input.len());
+        let line_idx = input[..offset].matches('\n').count();
+        ParseError::new(line_idx, input[line_start..offset].chars().count(), input[line_start..line_end].trim_end_matches('\r'), message)
+    }
+
+    /// Moves the error by the given number of lines and columns, e.g. if it was created for a snippet of the input.
+    pub fn offset_by(mut self, line_offset: usize, col_offset: usize) -> ParseError {
+        if self.line == 1 {
+            self.column += col_offset;
+        }
+        self.line += line_offset;
+        self
+    }
+
+    /// Sets the file the input has been read from.
+    pub fn with_file<P: AsRef<Path>>(mut self, path: P) -> ParseError {
+        self.file = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let location = match &self.file {
+            Some(path) => format!("{}:{}:{}", path.display(), self.line, self.column),
+            None => format!("<input>:{}:{}", self.line, self.column)
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{}--> {}", gutter, location)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let e = ParseError::new(2, 3, "1-x,5-6", "Could not parse number!").with_file("day4_input.txt");
+        assert_eq!(e.to_string(), "\
+Could not parse number!
+ --> day4_input.txt:3:4
+  |
+3 | 1-x,5-6
+  |    ^");
+    }
+
+    #[test]
+    fn offsets() {
+        let input = "abc\ndef\nghi";
+        let e = ParseError::at_offset(input, 5, "Unexpected character!");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "def"));
+
+        let e = ParseError::at_end(input, "Unexpected end of input!");
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 4, "ghi"));
+
+        let e = ParseError::new(0, 2, "xyz", "Error!").offset_by(4, 10);
+        assert_eq!((e.line, e.column), (5, 13));
+    }
+}
@@ -0,0 +1,14 @@
+//! Interface of the simulations of the grid-based days that can be stepped through frame by frame (used by the visualizer of the runner).
+
+/// Simulation that is advanced one frame at a time, where every frame is rendered as lines of characters. Cloning has to be reasonably
+/// cheap, since the visualizer keeps snapshots to be able to seek backwards.
+pub trait Visualization: Clone {
+    /// Advances the simulation by one frame. Returns false (without changing anything) if the simulation has already finished.
+    fn step(&mut self) -> bool;
+
+    /// Renders the current frame.
+    fn render(&self) -> String;
+
+    /// Describes the current frame in a few words for the status line (e.g. "Round 3 of 10").
+    fn status(&self) -> String;
+}
@@ -0,0 +1,229 @@
+use anyhow::{anyhow, bail, Context, Result};
+use common::{ParseError, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::read_to_string;
+use std::io::BufRead;
+use std::path::Path;
+
+pub mod stats;
+
+/// Calories of the items carried by each elf. Consecutive blank lines (and blank lines at the start or the end of the input) lead to
+/// empty groups, which are not counted as elves.
+pub type ElfCalorieList = Vec<Vec<usize>>;
+
+/// Total calories carried by an elf, where index is the zero-based position of the elf in the input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RankedElf {
+    pub index: usize,
+    pub calories: usize
+}
+
+/// Keeps the k highest ranked elves seen so far: More calories rank higher and for the same total, the elf that comes first in the input
+/// ranks higher.
+struct TopK {
+    k: usize,
+    /// Min-heap of the ranking keys (calories, Reverse(index)), i.e. the lowest ranked elf kept so far is on top
+    heap: BinaryHeap<Reverse<(usize, Reverse<usize>)>>
+}
+
+impl TopK {
+    fn new(k: usize) -> TopK {
+        TopK { k, heap: BinaryHeap::new() }
+    }
+
+    fn push(&mut self, elf: RankedElf) {
+        self.heap.push(Reverse((elf.calories, Reverse(elf.index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Returns the elves ordered from the highest to the lowest rank.
+    fn into_ranking(self) -> Vec<RankedElf> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| RankedElf { index, calories })
+            .collect()
+    }
+}
+
+pub fn parse_calorie_list(input: &str) -> Result<ElfCalorieList, ParseError> {
+    let lines = input.lines();
+
+    let mut elves = vec!();
+    let mut cur_elf_calorie_list = vec!();
+
+    for (line_idx, line) in lines.enumerate() {
+        if line.is_empty() {
+            elves.push(cur_elf_calorie_list);
+            cur_elf_calorie_list = vec!();
+        } else {
+            cur_elf_calorie_list.push(line
+                .parse()
+                .map_err(|_| ParseError::in_line(line_idx, line, format!("Could not parse \"{}\" from input as a number!", line)))?);
+        }
+    }
+
+    elves.push(cur_elf_calorie_list);
+
+    Ok(elves)
+}
+
+pub fn get_calorie_list_from_input<P: AsRef<Path>>(input_path: P) -> Result<ElfCalorieList> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_calorie_list(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+/// Returns the number of elves, i.e. the groups that are not empty.
+pub fn count_elves(ecl: &ElfCalorieList) -> usize {
+    ecl.iter().filter(|cl| !cl.is_empty()).count()
+}
+
+/// Returns the total calories carried by the elf with the given index (fails instead of overflowing).
+pub(crate) fn sum_calories(index: usize, cl: &[usize]) -> Result<usize> {
+    cl.iter().try_fold(0usize, |sum, c| sum.checked_add(*c)).with_context(|| format!("Total calories of elf {} overflow!", index + 1))
+}
+
+/// Returns the k elves carrying the most calories, ranked from the most to the fewest calories (ties are broken by the position in the
+/// input, so the elf that comes first ranks higher). Returns all elves if there are fewer than k, empty groups are skipped.
+pub fn top_k(ecl: &ElfCalorieList, k: usize) -> Result<Vec<RankedElf>> {
+    let mut top = TopK::new(k);
+    for (index, cl) in ecl.iter().enumerate().filter(|(_, cl)| !cl.is_empty()) {
+        top.push(RankedElf { index, calories: sum_calories(index, cl)? });
+    }
+
+    Ok(top.into_ranking())
+}
+
+/// Same as top_k, but reads the calorie list line by line from the reader instead of parsing it into an ElfCalorieList first, so only
+/// the current elf and the k best elves are kept in memory (for arbitrarily large inputs).
+pub fn top_k_from_reader<R: BufRead>(reader: R, k: usize) -> Result<Vec<RankedElf>> {
+    let mut top = TopK::new(k);
+    let mut cur_elf = RankedElf { index: 0, calories: 0 };
+    let mut cur_elf_has_items = false;
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line.context("Could not read input!")?;
+
+        if line.is_empty() {
+            if cur_elf_has_items {
+                top.push(cur_elf);
+            }
+            cur_elf = RankedElf { index: cur_elf.index + 1, calories: 0 };
+            cur_elf_has_items = false;
+        } else {
+            let error = |message: String| ParseError::in_line(line_idx, &line, message);
+            let calories: usize = line.parse().map_err(|_| error(format!("Could not parse \"{}\" from input as a number!", line)))?;
+            cur_elf.calories = cur_elf.calories.checked_add(calories).ok_or_else(|| error("Total calories of the elf overflow!".into()))?;
+            cur_elf_has_items = true;
+        }
+    }
+
+    if cur_elf_has_items {
+        top.push(cur_elf);
+    }
+
+    Ok(top.into_ranking())
+}
+
+pub fn get_max_calories(ecl: &ElfCalorieList) -> Result<usize> {
+    top_k(ecl, 1)?.first().map(|elf| elf.calories).ok_or(anyhow!("Elf calorie list was empty!"))
+}
+
+pub fn get_sum_of_top3_calories(ecl: &ElfCalorieList) -> Result<usize> {
+    let top3 = top_k(ecl, 3)?;
+    if top3.len() < 3 {
+        bail!("Elf calorie list contains only {} elves (at least three are needed)!", top3.len());
+    }
+
+    Ok(top3.iter().map(|elf| elf.calories).sum())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = ElfCalorieList;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<ElfCalorieList, ParseError> {
+        parse_calorie_list(input)
+    }
+
+    fn part1(ecl: &ElfCalorieList) -> Result<usize> {
+        get_max_calories(ecl)
+    }
+
+    fn part2(ecl: &ElfCalorieList) -> Result<usize> {
+        get_sum_of_top3_calories(ecl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_example() {
+        let calorie_list_per_elf = Day1::read_input("../inputs/day1_example.txt").unwrap();
+        assert_eq!(Day1::part1(&calorie_list_per_elf).unwrap(), 24000);
+        assert_eq!(Day1::part2(&calorie_list_per_elf).unwrap(), 45000);
+    }
+
+    #[test]
+    fn ranking() {
+        let input = read_to_string("../inputs/day1_example.txt").unwrap();
+        let ranking = top_k(&Day1::parse(&input).unwrap(), 4).unwrap();
+        let elf = |index, calories| RankedElf { index, calories };
+        assert_eq!(ranking, vec!(elf(3, 24000), elf(2, 11000), elf(4, 10000), elf(0, 6000)));
+        assert_eq!(top_k_from_reader(input.as_bytes(), 4).unwrap(), ranking);
+
+        // Elves with the same total are ranked by their position
+        let ecl = parse_calorie_list("100\n\n50\n\n60\n40\n\n100\n").unwrap();
+        assert_eq!(top_k(&ecl, 3).unwrap(), vec!(elf(0, 100), elf(2, 100), elf(3, 100)));
+        assert_eq!(top_k(&ecl, 4).unwrap()[3], elf(1, 50));
+        assert_eq!(top_k_from_reader("100\n\n50\n\n60\n40\n\n100\n".as_bytes(), 3).unwrap(), top_k(&ecl, 3).unwrap());
+
+        assert_eq!(top_k(&ecl, 0).unwrap(), vec!());
+        assert_eq!(top_k(&ecl, 10).unwrap().len(), 4);
+        assert_eq!(top_k_from_reader("".as_bytes(), usize::MAX).unwrap(), vec!());
+    }
+
+    #[test]
+    fn empty_groups() {
+        // Neither the blank line at the end nor the consecutive blank lines are elves
+        let input = "100\n\n\n200\n300\n\n";
+        let ecl = parse_calorie_list(input).unwrap();
+        assert_eq!((ecl.len(), count_elves(&ecl)), (4, 2));
+        assert_eq!(top_k(&ecl, 3).unwrap(), vec!(RankedElf { index: 2, calories: 500 }, RankedElf { index: 0, calories: 100 }));
+        assert_eq!(top_k_from_reader(input.as_bytes(), 3).unwrap(), top_k(&ecl, 3).unwrap());
+
+        let e = get_sum_of_top3_calories(&ecl).unwrap_err();
+        assert_eq!(e.to_string(), "Elf calorie list contains only 2 elves (at least three are needed)!");
+        assert!(get_max_calories(&parse_calorie_list("\n\n").unwrap()).is_err());
+    }
+
+    #[test]
+    fn too_few_elves() {
+        let ecl = parse_calorie_list("1000\n\n2000\n").unwrap();
+        let e = get_sum_of_top3_calories(&ecl).unwrap_err();
+        assert_eq!(e.to_string(), "Elf calorie list contains only 2 elves (at least three are needed)!");
+    }
+
+    #[test]
+    fn invalid_streamed_input() {
+        let e = top_k_from_reader("1000\n\n20x0\n".as_bytes(), 3).unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.text.as_str()), (3, "20x0"));
+
+        let e = top_k_from_reader(format!("{}\n1\n", usize::MAX).as_bytes(), 3).unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(e.line, 2);
+    }
+
+    #[test]
+    fn overflow() {
+        let ecl = vec!(vec!(1), vec!(usize::MAX, 1));
+        assert_eq!(top_k(&ecl, 3).unwrap_err().to_string(), "Total calories of elf 2 overflow!");
+    }
+}
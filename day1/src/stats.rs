@@ -0,0 +1,203 @@
+//! Statistics about the calories carried by the elves (printed by the day1 binary with --stats).
+
+use crate::{sum_calories, ElfCalorieList, RankedElf};
+use anyhow::{bail, Context, Result};
+use std::fmt::{self, Display, Formatter};
+
+/// Maximum number of buckets of the histogram.
+const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Length of the bar of the largest bucket in the rendered histogram.
+const HISTOGRAM_WIDTH: usize = 50;
+
+/// Number of elves whose total calories lie within [min, max] (both inclusive).
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    pub min: usize,
+    pub max: usize,
+    pub count: usize
+}
+
+/// Statistics about the total calories and the number of items of the elves. Empty groups are not counted as elves (like everywhere
+/// else), but the ones from consecutive blank lines within the input are reported separately with their index in the calorie list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InventoryStats {
+    pub elf_count: usize,
+    pub empty_groups: Vec<usize>,
+    pub total_calories: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub min_items: usize,
+    pub mean_items: f64,
+    pub max_items: usize,
+    pub histogram: Vec<HistogramBucket>,
+    /// Elves more than 1.5 times the interquartile range below the first or above the third quartile (in the order of the input)
+    pub outliers: Vec<RankedElf>
+}
+
+/// Returns the percentile (0-100) of the sorted values, interpolating linearly between the two closest values.
+fn percentile(sorted: &[usize], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, upper) = (sorted[rank.floor() as usize] as f64, sorted[rank.ceil() as usize] as f64);
+    lower + (upper - lower) * rank.fract()
+}
+
+fn calc_histogram(sorted: &[usize]) -> Vec<HistogramBucket> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    // Smallest width so that the buckets cover all max - min + 1 values (without overflowing for the full range of usize)
+    let width = (max - min) / HISTOGRAM_BUCKETS + 1;
+
+    let mut buckets: Vec<HistogramBucket> = (min..=max)
+        .step_by(width)
+        .map(|bucket_min| HistogramBucket { min: bucket_min, max: bucket_min.saturating_add(width - 1).min(max), count: 0 })
+        .collect();
+    for value in sorted {
+        buckets[(value - min) / width].count += 1;
+    }
+
+    buckets
+}
+
+pub fn calc_stats(ecl: &ElfCalorieList) -> Result<InventoryStats> {
+    // Empty groups at the start or the end come from a blank line at the start or the end of the input
+    let empty_groups: Vec<usize> = ecl
+        .iter()
+        .enumerate()
+        .filter(|(index, cl)| cl.is_empty() && *index > 0 && *index + 1 < ecl.len())
+        .map(|(index, _)| index)
+        .collect();
+    let elves: Vec<RankedElf> = ecl
+        .iter()
+        .enumerate()
+        .filter(|(_, cl)| !cl.is_empty())
+        .map(|(index, cl)| Ok(RankedElf { index, calories: sum_calories(index, cl)? }))
+        .collect::<Result<_>>()?;
+
+    if elves.is_empty() {
+        bail!("Elf calorie list does not contain any elf carrying items!");
+    }
+
+    let mut sorted: Vec<usize> = elves.iter().map(|elf| elf.calories).collect();
+    sorted.sort_unstable();
+    let total_calories = sorted.iter().try_fold(0usize, |sum, c| sum.checked_add(*c)).context("Total calories of all elves overflow!")?;
+
+    let (q1, q3) = (percentile(&sorted, 25.0), percentile(&sorted, 75.0));
+    let (lower_fence, upper_fence) = (q1 - 1.5 * (q3 - q1), q3 + 1.5 * (q3 - q1));
+    let outliers = elves.iter().filter(|elf| (elf.calories as f64) < lower_fence || (elf.calories as f64) > upper_fence).copied().collect();
+
+    let items = ecl.iter().filter(|cl| !cl.is_empty()).map(|cl| cl.len());
+
+    Ok(InventoryStats {
+        elf_count: elves.len(),
+        empty_groups,
+        total_calories,
+        mean: total_calories as f64 / elves.len() as f64,
+        median: percentile(&sorted, 50.0),
+        p90: percentile(&sorted, 90.0),
+        p99: percentile(&sorted, 99.0),
+        min_items: items.clone().min().unwrap(),
+        mean_items: items.clone().sum::<usize>() as f64 / elves.len() as f64,
+        max_items: items.max().unwrap(),
+        histogram: calc_histogram(&sorted),
+        outliers
+    })
+}
+
+/// Renders the statistics as text report (elves are numbered from 1 here, i.e. elf 1 is the first elf in the input).
+impl Display for InventoryStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Elves: {}", self.elf_count)?;
+        if !self.empty_groups.is_empty() {
+            let numbers: Vec<String> = self.empty_groups.iter().map(|index| (index + 1).to_string()).collect();
+            write!(f, " ({} empty group(s) from consecutive blank lines at position(s) {})", self.empty_groups.len(), numbers.join(", "))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "Total calories: {}", self.total_calories)?;
+        writeln!(f, "Calories per elf: mean {:.1}, median {:.1}, p90 {:.1}, p99 {:.1}", self.mean, self.median, self.p90, self.p99)?;
+        writeln!(f, "Items per elf: min {}, mean {:.1}, max {}", self.min_items, self.mean_items, self.max_items)?;
+
+        if self.outliers.is_empty() {
+            writeln!(f, "Outliers (1.5 IQR): none")?;
+        } else {
+            let outliers: Vec<String> = self.outliers
+                .iter()
+                .map(|elf| format!("elf {} ({} calories)", elf.index + 1, elf.calories))
+                .collect();
+            writeln!(f, "Outliers (1.5 IQR): {}", outliers.join(", "))?;
+        }
+
+        writeln!(f, "Histogram of calories per elf:")?;
+        let max_count = self.histogram.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+        let label_width = self.histogram.last().map(|b| b.max.to_string().len()).unwrap_or(0);
+        for b in &self.histogram {
+            let bar = "#".repeat((b.count * HISTOGRAM_WIDTH).div_ceil(max_count));
+            writeln!(f, "{:>w$} - {:>w$} | {} {}", b.min, b.max, bar, b.count, w = label_width)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_calorie_list, Day1};
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let ecl = Day1::read_input("../inputs/day1_example.txt").unwrap();
+        let stats = calc_stats(&ecl).unwrap();
+
+        assert_eq!((stats.elf_count, stats.total_calories), (5, 55000));
+        assert_eq!((stats.mean, stats.median, stats.p90, stats.p99), (11000.0, 10000.0, 18800.0, 23480.0));
+        assert_eq!((stats.min_items, stats.mean_items, stats.max_items), (1, 2.0, 3));
+        assert_eq!(stats.outliers, vec!(RankedElf { index: 3, calories: 24000 }));
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(stats.histogram[0], HistogramBucket { min: 4000, max: 6000, count: 2 });
+        assert_eq!(stats.histogram[9], HistogramBucket { min: 22009, max: 24000, count: 1 });
+        assert_eq!(stats.histogram.iter().map(|b| b.count).sum::<usize>(), 5);
+
+        let report = stats.to_string();
+        assert!(report.starts_with("Elves: 5\nTotal calories: 55000\n"));
+        assert!(report.contains("Outliers (1.5 IQR): elf 4 (24000 calories)\n"));
+        assert!(report.contains(" 4000 -  6000 | ################################################## 2\n"));
+    }
+
+    #[test]
+    fn single_elf() {
+        let stats = calc_stats(&parse_calorie_list("7\n").unwrap()).unwrap();
+        assert_eq!((stats.median, stats.p99), (7.0, 7.0));
+        assert_eq!(stats.histogram, vec!(HistogramBucket { min: 7, max: 7, count: 1 }));
+        assert!(stats.outliers.is_empty());
+    }
+
+    #[test]
+    fn empty_groups() {
+        let stats = calc_stats(&parse_calorie_list("100\n\n\n200\n300\n\n\n\n400\n").unwrap()).unwrap();
+        assert_eq!(stats.elf_count, 3);
+        assert_eq!(stats.empty_groups, vec!(1, 3, 4));
+        assert_eq!(stats.histogram.iter().map(|b| b.count).collect::<Vec<_>>(), vec!(1, 0, 0, 0, 0, 0, 0, 1, 0, 1));
+        assert!(stats.to_string().starts_with("Elves: 3 (3 empty group(s) from consecutive blank lines at position(s) 2, 4, 5)\n"));
+
+        assert!(calc_stats(&parse_calorie_list("\n\n").unwrap()).is_err());
+
+        // A single blank line at the end is no empty group from consecutive blank lines
+        let stats = calc_stats(&parse_calorie_list("100\n\n200\n\n").unwrap()).unwrap();
+        assert_eq!((stats.elf_count, stats.empty_groups.clone()), (2, vec!()));
+        assert!(stats.to_string().starts_with("Elves: 2\n"));
+    }
+
+    #[test]
+    fn large_values() {
+        let stats = calc_stats(&vec!(vec!(0), vec!(usize::MAX))).unwrap();
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(stats.histogram[9].max, usize::MAX);
+        assert_eq!(stats.histogram.iter().map(|b| b.count).sum::<usize>(), 2);
+
+        assert!(calc_stats(&vec!(vec!(usize::MAX), vec!(1))).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Context, Result};
+use common::{Answer, ParseError, Solution};
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub enum Instruction {
+    Noop,
+    Add { value: i32 }  // Only takes one cycle for execution (addx in the input is translated to a pair of Noop+Add)
+}
+
+/// Returns the sum of the signal strengths (first part of the puzzle) and the lines drawn on the CRT display (second part of the puzzle).
+pub fn process_instructions(instructions: &[Instruction]) -> (i32, Vec<String>) {
+    let mut x = 1;
+    let mut cur_cycle = 0;
+    let mut signal_strength_sum = 0;
+    let mut display_lines = vec!();
+    let mut cur_display_line = String::new();
+
+    for i in instructions {
+        let sprite_position: i32 = cur_cycle % 40;
+        cur_display_line.push(if (sprite_position - x).abs() <= 1 { '#' } else { '.' });
+
+        if cur_display_line.len() == 40 {
+            display_lines.push(cur_display_line);
+            cur_display_line = String::new();
+        }
+
+        cur_cycle += 1;
+
+        if cur_cycle == 20 || (cur_cycle - 20) % 40 == 0 {
+            signal_strength_sum += x * cur_cycle;
+        }
+
+        match i {
+            Instruction::Noop => {},
+            Instruction::Add { value } => {
+                x += value;
+            }
+        }
+    }
+
+    (signal_strength_sum, display_lines)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut res = vec!();
+
+    for (line_idx, l) in input.lines().enumerate() {
+        if l == "noop" {
+            res.push(Instruction::Noop);
+        } else if let Some(value_str) = l.strip_prefix("addx ") {
+            // Translate addx command to a pair of Noop and Add instructions (both of which take one cycle for execution)
+            res.push(Instruction::Noop);
+            res.push(Instruction::Add { value: value_str.parse().map_err(|_| ParseError::new(line_idx, 5, l, "Could not parse number!"))? });
+        } else {
+            return Err(ParseError::in_line(line_idx, l, "Unknown instruction (expected noop or addx)!"));
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Instruction>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<i32> {
+        Ok(process_instructions(instructions).0)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer> {
+        Ok(Answer::Grid(process_instructions(instructions).1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let instructions = Day10::read_input("../inputs/day10_example.txt").unwrap();
+        assert_eq!(Day10::part1(&instructions).unwrap(), 13140);
+        assert_eq!(Day10::part2(&instructions).unwrap().to_string(), "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....");
+    }
+}
@@ -0,0 +1,263 @@
+use anyhow::{ensure, Context, Result};
+use common::{ParseError, Solution};
+use itertools::Itertools;
+use num::integer::lcm;
+use regex::{Captures, Regex};
+use std::collections::VecDeque;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Clone, Debug)]
+pub enum Operator {
+    Add,
+    Mul
+}
+
+#[derive(Clone, Debug)]
+pub enum Operand {
+    Old,
+    Constant { val: u64 }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: VecDeque<u64>,  // Needs to be u64 for the first part of the puzzle already (u32 overflows during multiplication)
+    operator: Operator,
+    operand: Operand,
+    divisor: u64,
+    target_true: usize,
+    target_false: usize,
+    items_inspected_count: u64
+}
+
+/// Multiplies the inspection counts of the two most active monkeys.
+pub fn get_level_of_monkey_business(monkeys: &[Monkey]) -> Result<u64> {
+    ensure!(monkeys.len() >= 2, "At least two monkeys are required, but only {} given!", monkeys.len());
+
+    let mut inspection_counts: Vec<u64> = monkeys.iter().map(|m| m.items_inspected_count).collect();
+    inspection_counts.sort_unstable();
+    inspection_counts.reverse();
+    inspection_counts[0].checked_mul(inspection_counts[1]).context("Level of monkey business overflows!")
+}
+
+/// Parses the number in the given capture group, errors refer to the position in the input (the description of a monkey starts at
+/// first_line_idx).
+fn parse_capture<T: FromStr>(caps: &Captures, name: &str, monkey_description: &str, first_line_idx: usize) -> Result<T, ParseError> {
+    let m = caps.name(name).unwrap();  // All capture groups are mandatory in the regex
+    m.as_str().parse().map_err(|_| ParseError::at_offset(monkey_description, m.start(), "Could not parse number!").offset_by(first_line_idx, 0))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let re = Regex::new(r"^Monkey (?P<monkey_num>\d+):
+  Starting items: (?P<starting_items>[\d ,]+)
+  Operation: new = old (?P<operator>[+*]) (?P<operand>(old|\d+))
+  Test: divisible by (?P<divisor>\d+)
+    If true: throw to monkey (?P<target_true>\d+)
+    If false: throw to monkey (?P<target_false>\d+)$").unwrap();
+
+    let lines: Vec<&str> = input.lines().collect();
+    let mut res = vec!();
+
+    // Read input in chunks of 7 lines (six lines describing the monkey and an empty line)
+    for (chunk_idx, chunk) in lines.chunks(7).enumerate() {
+        let first_line_idx = chunk_idx * 7;
+        let monkey_description = chunk.iter().take(6).join("\n");
+        let caps = re
+            .captures(&monkey_description)
+            .ok_or_else(|| ParseError::in_line(first_line_idx, chunk[0], "Could not parse monkey description (expected six lines)!"))?;
+
+        if chunk.len() == 7 && !chunk[6].is_empty() {
+            return Err(ParseError::in_line(first_line_idx + 6, chunk[6], "Expected an empty line after the monkey description!"));
+        }
+
+        let monkey_num: usize = parse_capture(&caps, "monkey_num", &monkey_description, first_line_idx)?;
+        if monkey_num != chunk_idx {
+            return Err(ParseError::new(first_line_idx, 7, chunk[0], format!("Monkeys numbered wrongly! Expected number {}, but was {}.", chunk_idx, monkey_num)));
+        }
+
+        let starting_items = caps.name("starting_items").unwrap();
+        let monkey = Monkey {
+            items: starting_items
+                .as_str()
+                .split(", ")
+                .map(|s| s.parse().map_err(|_| ParseError::new(first_line_idx + 1, 18, chunk[1], "Could not parse starting items!")))
+                .collect::<Result<_, _>>()?,
+            operator: match caps.name("operator").unwrap().as_str() {
+                "+" => Operator::Add,
+                _ => Operator::Mul  // Regex only matches + and *
+            },
+            operand: match caps.name("operand").unwrap().as_str() {
+                "old" => Operand::Old,
+                _ => Operand::Constant { val: parse_capture(&caps, "operand", &monkey_description, first_line_idx)? }
+            },
+            divisor: match parse_capture(&caps, "divisor", &monkey_description, first_line_idx)? {
+                0 => return Err(ParseError::new(first_line_idx + 3, 21, chunk[3], "Divisor must not be zero!")),
+                divisor => divisor
+            },
+            target_true: parse_capture(&caps, "target_true", &monkey_description, first_line_idx)?,
+            target_false: parse_capture(&caps, "target_false", &monkey_description, first_line_idx)?,
+            items_inspected_count: 0
+        };
+
+        res.push(monkey);
+    }
+
+    if res.is_empty() {
+        return Err(ParseError::at_end(input, "No monkeys found!"));
+    }
+
+    // Make sure that items are only thrown to monkeys that exist
+    for (monkey_idx, monkey) in res.iter().enumerate() {
+        for (line_offset, target) in [(4, monkey.target_true), (5, monkey.target_false)] {
+            if target >= res.len() {
+                let line_idx = monkey_idx * 7 + line_offset;
+                return Err(ParseError::in_line(line_idx, lines[line_idx], format!("Monkey {} does not exist!", target)));
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Monkey>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+/// Monkey vector is altered with the changes made during this round.
+pub fn simulate_round(monkeys: &mut [Monkey], enable_divide_by_three: bool) -> Result<()> {
+    // Calculate least common multiple of all divisors: Necessary for numerical optimization for the second part of the puzzle (see below)
+    let all_divisors_lcm = monkeys.iter().map(|m| m.divisor).reduce(lcm).context("No monkeys given!")?;
+
+    for i in 0..monkeys.len() {
+        while let Some(worry) = monkeys[i].items.pop_front() {
+            // Apply operation for this monkey
+            let operand = match monkeys[i].operand {
+                Operand::Old => worry,
+                Operand::Constant { val } => val
+            };
+            let mut worry = match monkeys[i].operator {
+                Operator::Add => worry.checked_add(operand),
+                Operator::Mul => worry.checked_mul(operand)
+            }
+            .with_context(|| format!("Worry level of an item with worry level {} overflows when inspected by monkey {}!", worry, i))?;
+
+            if enable_divide_by_three {
+                // Post-inspection division by three
+                worry /= 3;
+            } else {
+                // Prevent numbers from getting to large (for second part of the puzzle)
+                // We are running all calculations in the space modulo-X (where X should be the least common multiple of all divisors)
+                worry %= all_divisors_lcm;
+            }
+
+            // Evaluate condition
+            let is_divisible = worry % monkeys[i].divisor == 0;
+
+            // Pass item to target monkey
+            let target_monkey = if is_divisible { monkeys[i].target_true } else { monkeys[i].target_false };
+            monkeys[target_monkey].items.push_back(worry);
+
+            // Increase item inspection count
+            monkeys[i].items_inspected_count += 1;
+        }
+    }
+
+    Ok(())
+}
+
+/// Simulates the given number of rounds on a copy of the monkeys and returns the resulting level of monkey business.
+fn simulate_rounds(monkeys: &[Monkey], num_rounds: usize, enable_divide_by_three: bool) -> Result<u64> {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..num_rounds {
+        simulate_round(&mut monkeys, enable_divide_by_three)?;
+    }
+
+    get_level_of_monkey_business(&monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>) -> Result<u64> {
+        simulate_rounds(monkeys, 20, true)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>) -> Result<u64> {
+        simulate_rounds(monkeys, 10000, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let mut monkeys = read_input_file("../inputs/day11_example.txt").unwrap();
+        assert_eq!(Day11::part1(&monkeys).unwrap(), 10605);
+
+        // Simulate first round and check items
+        simulate_round(&mut monkeys, true).unwrap();
+        assert_eq!(monkeys[0].items, [20, 23, 27, 26]);
+        assert_eq!(monkeys[1].items, [2080, 25, 167, 207, 401, 1046]);
+        assert!(monkeys[2].items.is_empty());
+        assert!(monkeys[3].items.is_empty());
+
+        // Simulate 19 more rounds and check inspection counts
+        for _ in 0..19 {
+            simulate_round(&mut monkeys, true).unwrap();
+        }
+        assert_eq!(monkeys[0].items_inspected_count, 101);
+        assert_eq!(monkeys[1].items_inspected_count, 95);
+        assert_eq!(monkeys[2].items_inspected_count, 7);
+        assert_eq!(monkeys[3].items_inspected_count, 105);
+    }
+
+    #[test]
+    fn example2() {
+        let mut monkeys = read_input_file("../inputs/day11_example.txt").unwrap();
+        assert_eq!(Day11::part2(&monkeys).unwrap(), 2713310158);
+
+        for _ in 0..10000 {
+            simulate_round(&mut monkeys, false).unwrap();
+        }
+        assert_eq!(monkeys[0].items_inspected_count, 52166);
+        assert_eq!(monkeys[1].items_inspected_count, 47830);
+        assert_eq!(monkeys[2].items_inspected_count, 1938);
+        assert_eq!(monkeys[3].items_inspected_count, 52013);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(parse_input("").unwrap_err().message, "No monkeys found!");
+        assert!(simulate_round(&mut [], true).is_err());
+
+        let input = read_to_string("../inputs/day11_example.txt").unwrap();
+        let e = parse_input(&input.replacen("divisible by 23", "divisible by 0", 1)).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (4, 22, "Divisor must not be zero!"));
+
+        // A single monkey squaring its item's worry level
+        let mut monkeys = parse_input("Monkey 0:
+  Starting items: 4294967296
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+").unwrap();
+        let err = simulate_round(&mut monkeys, true).unwrap_err();
+        assert_eq!(err.to_string(), "Worry level of an item with worry level 4294967296 overflows when inspected by monkey 0!");
+        assert_eq!(get_level_of_monkey_business(&monkeys).unwrap_err().to_string(),
+            "At least two monkeys are required, but only 1 given!");
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::{bail, Context, Result};
+use common::{ParseError, Solution};
+use grid::{Grid, Pos};
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub struct Input {
+    height_map: Grid<u8>,
+    start_pos: Pos,
+    end_pos: Pos
+}
+
+/// Computes the length of the shortest path with a breadth-first search (all steps have the same cost).
+/// (Note: We are traversing the grid from the end to the start position to allow for an easy solution to the second part of the puzzle,
+/// where we can end at any height-zero-field. Whether this is allowed, is set by the allow_end_at_any_height0_position parameter.)
+pub fn get_optimal_step_count(input: &Input, allow_end_at_any_height0_position: bool) -> Result<usize> {
+    // Neighbors that could reach the current node (their height >= current height - 1)
+    let predecessors = |pos: &Pos| {
+        let cur_height = input.height_map[*pos];
+        input.height_map.neighbours4(*pos).filter(move |n| input.height_map[*n] + 1 >= cur_height)
+    };
+
+    let is_goal = |pos: &Pos| {
+        if allow_end_at_any_height0_position { input.height_map[*pos] == 0 } else { *pos == input.start_pos }
+    };
+
+    match search::bfs([input.end_pos], predecessors, is_goal) {
+        Some(path) => Ok(path.cost),
+        None => bail!("No path found!")
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut start_pos = None;
+    let mut end_pos = None;
+
+    let height_map = Grid::parse(input, |pos, c| match c {
+        'S' => {
+            start_pos = Some(pos);
+            Ok(0)
+        },
+        'E' => {
+            end_pos = Some(pos);
+            Ok(25)
+        },
+        'a'..='z' => Ok(c as u8 - b'a'),  // Convert to digit where 'a' is 0 and 'z' is 25
+        _ => Err(format!("Invalid height character: {}", c))
+    })?;
+
+    Ok(Input {
+        height_map,
+        start_pos: start_pos.ok_or_else(|| ParseError::at_end(input, "No start position found!"))?,
+        end_pos: end_pos.ok_or_else(|| ParseError::at_end(input, "No end position found!"))?
+    })
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Input> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Input) -> Result<usize> {
+        get_optimal_step_count(input, false)
+    }
+
+    fn part2(input: &Input) -> Result<usize> {
+        get_optimal_step_count(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let input = Day12::read_input("../inputs/day12_example.txt").unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 31);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day12::read_input("../inputs/day12_example.txt").unwrap();
+        assert_eq!(Day12::part2(&input).unwrap(), 29);
+    }
+}
@@ -0,0 +1,254 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use itertools::{EitherOrBoth, Itertools};
+use std::cmp::Ordering;
+use std::fs::read_to_string;
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Element {
+    Number(u32),
+    List(Vec<Element>)
+}
+
+pub type Pair = (Element, Element);
+
+/// Maximum nesting depth of lists in a packet (parsing and comparing packets recurses once per level).
+pub const MAX_NESTING_DEPTH: usize = 100;
+
+fn check_if_pair_is_in_right_order(e1: &Element, e2: &Element) -> Ordering {
+    match (e1, e2) {
+        (Element::Number(a), Element::Number(b)) => {
+            if a < b { Ordering::Less }
+            else if a == b { Ordering::Equal }
+            else { Ordering::Greater }
+        },
+        (Element::Number(_), Element::List(_)) => {
+            check_if_pair_is_in_right_order(&Element::List(vec!(e1.clone())), e2)
+        },
+        (Element::List(_), Element::Number(_)) => {
+            check_if_pair_is_in_right_order(e1, &Element::List(vec!(e2.clone())))
+        },
+        (Element::List(a), Element::List(b)) => {
+            for i in a.iter().zip_longest(b) {
+                match i {
+                    EitherOrBoth::Both(x, y) => {
+                        let res = check_if_pair_is_in_right_order(x, y);
+                        if res != Ordering::Equal {
+                            return res;
+                        }
+                    },
+                    EitherOrBoth::Left(_) => { return Ordering::Greater; }
+                    EitherOrBoth::Right(_) => { return Ordering::Less; }
+                }
+            }
+
+            Ordering::Equal
+        }
+    }
+}
+
+pub fn get_indices_of_correct_pairs(pairs: &[Pair]) -> Vec<usize> {
+    let mut res = vec!();
+
+    for (i, p) in pairs.iter().enumerate() {
+        if check_if_pair_is_in_right_order(&p.0, &p.1) == Ordering::Less {
+            res.push(i + 1);
+        }
+    }
+
+    res
+}
+
+/// Parses a packet (or an element of a packet) that starts at the given column of the input line and is nested in depth lists. Errors
+/// contain the column where the problem has been detected.
+fn parse_packet(expr: &str, col_idx: usize, depth: usize) -> Result<Element, (usize, String)> {
+    // Are we reading a list?
+    if expr.len() >= 2 && expr.starts_with('[') && expr.ends_with(']') {
+        if depth >= MAX_NESTING_DEPTH {
+            return Err((col_idx, format!("Lists are nested deeper than {} levels!", MAX_NESTING_DEPTH)));
+        }
+
+        let list_str = &expr[1..expr.len() - 1];
+
+        // Split list into elements, separating by comma (but not splitting inside sublists)
+        let mut nested_level = 0;
+        let mut res = vec!();
+        let mut element_start = 0;
+
+        for (i, c) in list_str.char_indices() {
+            if nested_level == 0 && c == ',' {
+                res.push(parse_packet(&list_str[element_start..i], col_idx + 1 + element_start, depth + 1)?);
+                element_start = i + 1;
+            } else if c == '[' {
+                nested_level += 1;
+            } else if c == ']' {
+                if nested_level == 0 {
+                    return Err((col_idx + 1 + i, "Closing bracket without matching opening bracket!".into()));
+                }
+
+                nested_level -= 1;
+            }
+        }
+
+        if nested_level != 0 {
+            return Err((col_idx + expr.len() - 1, "Nesting wrong: Opening bracket without matching closing bracket!".into()));
+        }
+
+        // Last element (an empty string is only allowed for the empty list)
+        if element_start > 0 || !list_str.is_empty() {
+            res.push(parse_packet(&list_str[element_start..], col_idx + 1 + element_start, depth + 1)?);
+        }
+
+        Ok(Element::List(res))
+    }
+
+    // Otherwise, the string must contain just a single number
+    else if let Ok(n) = expr.parse::<u32>() {
+        Ok(Element::Number(n))
+    }
+
+    else {
+        Err((col_idx, format!("Could not parse \"{}\" as list or number!", expr)))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut res = vec!();
+
+    let parse_line = |line_idx: usize| {
+        let line = lines.get(line_idx).ok_or_else(|| ParseError::at_end(input, "Expected another packet!"))?;
+        parse_packet(line, 0, 0).map_err(|(col_idx, message)| ParseError::new(line_idx, col_idx, line, message))
+    };
+
+    // Read input in chunks of 3 lines
+    for first_line_idx in (0..lines.len()).step_by(3) {
+        let pair = (parse_line(first_line_idx)?, parse_line(first_line_idx + 1)?);
+
+        if let Some(l) = lines.get(first_line_idx + 2) {
+            if !l.is_empty() {
+                return Err(ParseError::in_line(first_line_idx + 2, l, "No empty line in between pairs found!"));
+            }
+        }
+
+        res.push(pair);
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Pair>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub fn sort_and_get_divider_indices_product(packets: &mut Vec<Element>) -> usize {
+    // Add divider packets
+    let divider1 = Element::List(vec!(Element::List(vec!(Element::Number(2)))));
+    let divider2 = Element::List(vec!(Element::List(vec!(Element::Number(6)))));
+    packets.push(divider1.clone());
+    packets.push(divider2.clone());
+
+    // Sort whole list of packets using comparison function
+    packets.sort_unstable_by(check_if_pair_is_in_right_order);
+
+    // Determine (1-based) indices of divider packets and return product
+    (packets.iter().position(|e| *e == divider1).expect("Divider packet not found") + 1) *
+        (packets.iter().position(|e| *e == divider2).expect("Divider packet not found") + 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(pairs: &Vec<Pair>) -> Result<usize> {
+        Ok(get_indices_of_correct_pairs(pairs).iter().sum())
+    }
+
+    fn part2(pairs: &Vec<Pair>) -> Result<usize> {
+        let mut all_packets: Vec<Element> = pairs.iter().flat_map(|p| [p.0.clone(), p.1.clone()].into_iter()).collect();
+        Ok(sort_and_get_divider_indices_product(&mut all_packets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let pairs = Day13::read_input("../inputs/day13_example.txt").unwrap();
+        assert_eq!(Day13::part1(&pairs).unwrap(), 13);
+        assert_eq!(Day13::part2(&pairs).unwrap(), 140);
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let input = format!("{}\n[]\n", nested(MAX_NESTING_DEPTH));
+        assert_eq!(Day13::part1(&parse_input(&input).unwrap()).unwrap(), 0);
+
+        let e = parse_input(&format!("[]\n{}\n", nested(MAX_NESTING_DEPTH + 1))).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, MAX_NESTING_DEPTH + 1, "Lists are nested deeper than 100 levels!"));
+        assert!(parse_input(&format!("{}\n[]\n", nested(50000))).is_err());
+    }
+
+    /// Packets with small numbers and shallow nesting, so that equal prefixes (and thus all comparison cases) occur frequently.
+    fn packet() -> impl Strategy<Value = Element> {
+        let element = (0..5u32).prop_map(Element::Number).prop_recursive(3, 16, 4, |inner| {
+            prop::collection::vec(inner, 0..4).prop_map(Element::List)
+        });
+        prop::collection::vec(element, 0..4).prop_map(Element::List)
+    }
+
+    fn format_packet(e: &Element) -> String {
+        match e {
+            Element::Number(n) => n.to_string(),
+            Element::List(l) => format!("[{}]", l.iter().map(format_packet).join(","))
+        }
+    }
+
+    /// Puzzle input in the format "packet\npacket\n\npacket\npacket\n..." together with the generated pairs.
+    fn pairs_input() -> impl Strategy<Value = (String, Vec<Pair>)> {
+        prop::collection::vec((packet(), packet()), 1..10).prop_map(|pairs| {
+            let input = pairs.iter().map(|(a, b)| format!("{}\n{}\n", format_packet(a), format_packet(b))).join("\n");
+            (input, pairs)
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn parse_round_trip((input, pairs) in pairs_input()) {
+            prop_assert_eq!(parse_input(&input).unwrap(), pairs);
+        }
+
+        #[test]
+        fn comparator_is_total_order(a in packet(), b in packet(), c in packet()) {
+            prop_assert_eq!(check_if_pair_is_in_right_order(&a, &a), Ordering::Equal);
+            prop_assert_eq!(check_if_pair_is_in_right_order(&a, &b), check_if_pair_is_in_right_order(&b, &a).reverse());
+
+            // Transitivity: a <= b and b <= c imply a <= c (strictly if one of the two is strict)
+            let (ab, bc) = (check_if_pair_is_in_right_order(&a, &b), check_if_pair_is_in_right_order(&b, &c));
+            if ab != Ordering::Greater && bc != Ordering::Greater {
+                prop_assert_eq!(check_if_pair_is_in_right_order(&a, &c), ab.then(bc));
+            }
+        }
+
+        #[test]
+        fn sorting_orders_all_packets(mut packets in prop::collection::vec(packet(), 0..20)) {
+            sort_and_get_divider_indices_product(&mut packets);
+            prop_assert!(packets.windows(2).all(|w| check_if_pair_is_in_right_order(&w[0], &w[1]) != Ordering::Greater));
+        }
+    }
+}
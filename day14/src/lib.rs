@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{Pos, SparseGrid};
+use std::cmp::{min, max};
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub mod visualization;
+
+#[derive(Clone)]
+pub struct Field {
+    /// We do not actually need to store the field as an array, we can just keep a set of the occupied fields
+    occupied_fields: SparseGrid,
+
+    /// If a falling sand unit exceeds the maximum depth, we can assume it has fallen into the abyss
+    max_depth: i32
+}
+
+/// add_virtual_floor controls whether there is a virtual floor at max_depth + 2 for the second part of the puzzle.
+pub fn calc_fallen_sand_count(f: &mut Field, add_virtual_floor: bool) -> Result<u32> {
+    let mut count = 0;
+
+    while simulate_sand_unit(f, add_virtual_floor).is_some() {
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Parses a coordinate "x,y" that starts at the given column of the input line.
+fn parse_coord(line_idx: usize, line: &str, col_idx: usize, s: &str) -> Result<Pos, ParseError> {
+    let error = |message: String| ParseError::new(line_idx, col_idx, line, message);
+    let (x_str, y_str) = s.split_once(',').ok_or_else(|| error(format!("Could not parse \"{}\" as coordinate x,y!", s)))?;
+
+    let x = x_str.parse().map_err(|_| error(format!("Could not parse X: {}", s)))?;
+    let y = y_str.parse().map_err(|_| error(format!("Could not parse Y: {}", s)))?;
+
+    Ok((x, y))
+}
+
+pub fn parse_input(input: &str) -> Result<Field, ParseError> {
+    let mut occupied_fields = SparseGrid::new();
+    let mut max_depth = 0;
+
+    for (line_idx, line) in input.lines().enumerate() {
+        let mut coord_strings = line.split(" -> ");
+        let first_coord_str = coord_strings.next().unwrap();  // Splitting always returns at least one (possibly empty) string
+        let mut cur_coord = parse_coord(line_idx, line, 0, first_coord_str)?;
+        let mut col_idx = first_coord_str.len() + 4;
+
+        for next_coord_str in coord_strings {
+            let next_coord = parse_coord(line_idx, line, col_idx, next_coord_str)?;
+
+            // Mark all fields from the current to the next coordinate as occupied
+            if cur_coord.0 == next_coord.0 {
+                let max_y = max(cur_coord.1, next_coord.1);
+
+                for y in min(cur_coord.1, next_coord.1)..=max_y {
+                    occupied_fields.insert((cur_coord.0, y), ());
+                }
+
+                if max_y > max_depth {
+                    max_depth = max_y;
+                }
+            } else if cur_coord.1 == next_coord.1 {
+                for x in min(cur_coord.0, next_coord.0)..=max(cur_coord.0, next_coord.0) {
+                    occupied_fields.insert((x, cur_coord.1), ());
+                }
+
+                if cur_coord.1 > max_depth {
+                    max_depth = cur_coord.1;
+                }
+            } else {
+                return Err(ParseError::new(line_idx, col_idx, line,
+                    format!("Connection between {:?} and {:?} is neither horizontal nor vertical!", cur_coord, next_coord)));
+            }
+
+            cur_coord = next_coord;
+            col_idx += next_coord_str.len() + 4;
+        }
+    }
+
+    Ok(Field {
+        occupied_fields,
+        max_depth
+    })
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Field> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+/// Simulates one unit of sand falling, adding it to the field (if it comes to rest on the field). Returns the position where the unit of
+/// sand came to rest on the field or None if it has fallen in the abyss (without virtual floor resp. first part of the puzzle) or if the
+/// spawn point is already blocked (with virtual floor resp. second part of the puzzle).
+fn simulate_sand_unit(f: &mut Field, add_virtual_floor: bool) -> Option<Pos> {
+    let mut cur_pos: Pos = (500, 0);
+
+    // Check if spawn position is already blocked
+    if f.occupied_fields.contains(cur_pos) {
+        return None;
+    }
+
+    while add_virtual_floor || cur_pos.1 < f.max_depth {
+        // Check if any of the fields below the sand unit is empty (if so, move there)
+        if add_virtual_floor && cur_pos.1 == f.max_depth + 1 {
+            // Sand unit cannot move down anymore and comes to rest on the floor
+            f.occupied_fields.insert(cur_pos, ());
+            return Some(cur_pos);
+        } else if !f.occupied_fields.contains((cur_pos.0, cur_pos.1 + 1)) {
+            cur_pos.1 += 1;
+        } else if !f.occupied_fields.contains((cur_pos.0 - 1, cur_pos.1 + 1)) {
+            cur_pos.0 -= 1;
+            cur_pos.1 += 1;
+        } else if !f.occupied_fields.contains((cur_pos.0 + 1, cur_pos.1 + 1)) {
+            cur_pos.0 += 1;
+            cur_pos.1 += 1;
+        } else {
+            // Sand unit cannot move anywhere and comes to rest - check if sand unit is now blocking the spawn position
+            f.occupied_fields.insert(cur_pos, ());
+            return Some(cur_pos);
+        }
+    }
+
+    None
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Field;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Field, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(field: &Field) -> Result<u32> {
+        calc_fallen_sand_count(&mut field.clone(), false)
+    }
+
+    fn part2(field: &Field) -> Result<u32> {
+        calc_fallen_sand_count(&mut field.clone(), true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let field = Day14::read_input("../inputs/day14_example.txt").unwrap();
+        assert_eq!(Day14::part1(&field).unwrap(), 24);
+    }
+
+    #[test]
+    fn example_part2() {
+        let field = Day14::read_input("../inputs/day14_example.txt").unwrap();
+        assert_eq!(Day14::part2(&field).unwrap(), 93);
+    }
+}
@@ -0,0 +1,82 @@
+//! Frame-by-frame visualization of the falling sand, where every frame adds one unit of sand.
+
+use crate::{simulate_sand_unit, Field};
+use common::Visualization;
+use grid::SparseGrid;
+
+#[derive(Clone)]
+pub struct SandVisualization {
+    field: Field,
+    add_virtual_floor: bool,
+    /// Positions of the units of sand at rest (the field does not distinguish them from rock)
+    sand: SparseGrid,
+    finished: bool
+}
+
+impl SandVisualization {
+    pub fn new(field: &Field, add_virtual_floor: bool) -> Self {
+        SandVisualization { field: field.clone(), add_virtual_floor, sand: SparseGrid::new(), finished: false }
+    }
+}
+
+impl Visualization for SandVisualization {
+    fn step(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+
+        match simulate_sand_unit(&mut self.field, self.add_virtual_floor) {
+            Some(pos) => {
+                self.sand.insert(pos, ());
+                true
+            },
+            None => {
+                self.finished = true;
+                false
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        let occupied = &self.field.occupied_fields;
+        let floor_y = self.field.max_depth + 2;
+        let (min, max) = occupied.bounds().unwrap_or(((500, 0), (500, 0)));
+
+        grid::render_area((min.0.min(500) - 1, 0), (max.0.max(500) + 1, floor_y), |pos| {
+            if self.sand.contains(pos) { 'o' }
+            else if occupied.contains(pos) || (self.add_virtual_floor && pos.1 == floor_y) { '#' }
+            else if pos == (500, 0) { '+' }
+            else { '.' }
+        })
+    }
+
+    fn status(&self) -> String {
+        format!("{} units of sand at rest{}", self.sand.len(), if self.finished { " (finished)" } else { "" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let field = Day14::read_input("../inputs/day14_example.txt").unwrap();
+
+        for (add_virtual_floor, sand_count) in [(false, 24), (true, 93)] {
+            let mut v = SandVisualization::new(&field, add_virtual_floor);
+            while v.step() {}
+            assert_eq!(v.sand.len(), sand_count);
+            assert!(!v.step());
+        }
+
+        // Final state of the first part as shown in the puzzle description (the rendering has an additional column on both sides)
+        let mut v = SandVisualization::new(&field, false);
+        while v.step() {}
+        let rendered: Vec<String> = v.render().lines().take(10).map(|l| l[1..11].to_string()).collect();
+        assert_eq!(rendered, vec!("......+...", "..........", "......o...", ".....ooo..", "....#ooo##", "...o#ooo#.", "..###ooo#.",
+            "....oooo#.", ".o.ooooo#.", "#########."));
+    }
+}
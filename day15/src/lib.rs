@@ -0,0 +1,203 @@
+use anyhow::{anyhow, Context, Result};
+use common::{ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use regex::Regex;
+use std::cmp::max;
+use std::fs::read_to_string;
+use std::ops::Sub;
+use std::path::Path;
+use tracing::trace;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Position(pub i64, pub i64);
+
+impl Sub for &Position {
+    type Output = u64;
+
+    // Manhattan distance (saturating, since such a distance is farther than any sensor can reach anyway)
+    fn sub(self, other: Self) -> Self::Output {
+        self.0.abs_diff(other.0).saturating_add(self.1.abs_diff(other.1))
+    }
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    position: Position,
+    closest_beacon: Position,
+    closest_beacon_dist: u64
+}
+
+pub fn calc_positions_without_beacon(sensors: &[Sensor], row_y: i64) -> usize {
+    // Determine start and end position for scanning X positions
+    let start_x = sensors.iter().map(|sensor| sensor.position.0 - sensor.closest_beacon_dist as i64).min().unwrap();
+    let end_x = sensors.iter().map(|sensor| sensor.position.0 + sensor.closest_beacon_dist as i64).max().unwrap();
+
+    (start_x..=end_x).filter(|x| {
+        let cur_pos = Position(*x, row_y);
+
+        let is_beacon = sensors.iter().any(|sensor| {
+            cur_pos == sensor.closest_beacon
+        });
+
+        // Loop over all sensors and check whether we are closer to a sensor than its closest_beacon_dist (which means that our current
+        // position cannot possibly contain a beacon)
+        let cannot_be_other_beacon = !is_beacon && sensors.iter().any(|sensor| {
+            &cur_pos - &sensor.position <= sensor.closest_beacon_dist
+        });
+
+        trace!(x, is_beacon, cannot_be_other_beacon);
+        cannot_be_other_beacon
+    }).count()
+}
+
+pub fn calc_tuning_frequency(pos: &Position) -> i64 {
+    pos.0 * 4000000 + pos.1
+}
+
+/// Scans the given column for a position that is not covered by any sensor.
+fn find_missing_beacon_in_column(sensors: &[Sensor], x: i64, max_coord: i64) -> Option<Position> {
+    let mut y = 0;
+
+    while y <= max_coord {
+        let cur_pos = Position(x, y);
+
+        // Check why no beacon can exist at this position: Calculate for each sensors how much closer we are to the sensor than the
+        // sensor's closest beacon
+        let closer_to_sensor_than_closest_beacon = sensors.iter().filter_map(|sensor| {
+            let dist_to_sensor = &cur_pos - &sensor.position;
+
+            if dist_to_sensor <= sensor.closest_beacon_dist {
+                Some(sensor.closest_beacon_dist - dist_to_sensor)
+            } else {
+                None
+            }
+        }).max();
+
+        if let Some(dist) = closer_to_sensor_than_closest_beacon {
+            // Skip this many fields: If we are, e.g., 5 units closer to a sensor than its closest beacon, none of the next five fields can
+            // contain a beacon (otherwise, it would be the closest beacon to this sensor)
+            y += max(dist as i64, 1);
+        } else {
+            // We found the missing beacon
+            return Some(cur_pos);
+        }
+    }
+
+    None
+}
+
+pub fn find_missing_beacon(sensors: &[Sensor], max_coord: i64) -> Result<Position> {
+    // The columns are independent of each other, so they can be scanned in parallel (the first match is returned like in the serial case)
+    #[cfg(feature = "parallel")]
+    let beacon = (0..=max_coord).into_par_iter().find_map_first(|x| find_missing_beacon_in_column(sensors, x, max_coord));
+    #[cfg(not(feature = "parallel"))]
+    let beacon = (0..=max_coord).find_map(|x| find_missing_beacon_in_column(sensors, x, max_coord));
+
+    beacon.ok_or_else(|| anyhow!("No beacon found!"))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let re = Regex::new(r"^Sensor at x=(?P<sensor_x>[-\d]+), y=(?P<sensor_y>[-\d]+): closest beacon is at x=(?P<beacon_x>[-\d]+), y=(?P<beacon_y>[-\d]+)$").unwrap();
+
+    let sensors = input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| {
+            let caps = re.captures(l).ok_or_else(|| ParseError::in_line(line_idx, l, "Could not parse sensor description!"))?;
+            let parse_number = |name: &str| {
+                let m = caps.name(name).unwrap();  // All capture groups are mandatory in the regex
+                m.as_str().parse().map_err(|_| ParseError::new(line_idx, m.start(), l, "Could not parse number!"))
+            };
+
+            let position = Position(parse_number("sensor_x")?, parse_number("sensor_y")?);
+            let closest_beacon = Position(parse_number("beacon_x")?, parse_number("beacon_y")?);
+
+            // The solver scans the range of the sensor in all directions, so its bounds must be representable
+            let beacon_col_idx = caps.name("beacon_x").unwrap().start();
+            let out_of_range = || ParseError::new(line_idx, beacon_col_idx, l, "Beacon is too far away from the sensor!");
+            let closest_beacon_dist = closest_beacon.0.abs_diff(position.0).checked_add(closest_beacon.1.abs_diff(position.1));
+            let range = closest_beacon_dist.and_then(|d| i64::try_from(d).ok()).ok_or_else(out_of_range)?;
+            if [position.0, position.1].iter().any(|c| c.checked_sub(range).is_none() || c.checked_add(range).is_none()) {
+                return Err(out_of_range());
+            }
+            let closest_beacon_dist = range as u64;
+
+            Ok(Sensor {
+                position,
+                closest_beacon,
+                closest_beacon_dist
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if sensors.is_empty() {
+        return Err(ParseError::at_end(input, "No sensors found!"));
+    }
+    Ok(sensors)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Sensor>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(sensors: &Vec<Sensor>) -> Result<usize> {
+        Ok(calc_positions_without_beacon(sensors, 2000000))
+    }
+
+    fn part2(sensors: &Vec<Sensor>) -> Result<i64> {
+        Ok(calc_tuning_frequency(&find_missing_beacon(sensors, 4000000)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let sensors = read_input_file("../inputs/day15_example.txt").unwrap();
+        assert_eq!(calc_positions_without_beacon(&sensors, 10), 26);
+        assert_eq!(find_missing_beacon(&sensors, 20).unwrap(), Position(14, 11));
+        assert_eq!(calc_tuning_frequency(&Position(14, 11)), 56000011);
+    }
+
+    #[test]
+    fn no_sensors() {
+        assert_eq!(parse_input("").unwrap_err().message, "No sensors found!");
+        assert_eq!(parse_input("\n").unwrap_err().message, "Could not parse sensor description!");
+    }
+
+    #[test]
+    fn extreme_coordinates() {
+        let e = parse_input("Sensor at x=9223372036854775807, y=0: closest beacon is at x=-9223372036854775808, y=0").unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (1, 62, "Beacon is too far away from the sensor!"));
+        let e = parse_input("Sensor at x=0, y=-9223372036854775800: closest beacon is at x=0, y=0\n").unwrap_err();
+        assert_eq!(e.message, "Beacon is too far away from the sensor!");
+
+        // Far away from the scanned row
+        let sensors = parse_input("Sensor at x=0, y=9223372036854775000: closest beacon is at x=1, y=9223372036854775000\n").unwrap();
+        assert_eq!(calc_positions_without_beacon(&sensors, -9223372036854775000), 0);
+
+        // Parsing must not panic for any combination of extreme coordinates
+        let extremes = [i64::MIN, -1, 0, i64::MAX];
+        let pairs: Vec<(i64, i64)> = extremes.iter().flat_map(|x| extremes.map(|y| (*x, y))).collect();
+        for (sensor_x, sensor_y) in &pairs {
+            for (beacon_x, beacon_y) in &pairs {
+                let _ = parse_input(&format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}", sensor_x, sensor_y, beacon_x, beacon_y));
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use anyhow::{Context, Result};
+use common::{CancelToken, ParseError, Solution, TimedOut};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::debug;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[derive(Debug)]
+pub struct Valve {
+    flow_rate: u32,
+    tunnels_to: Vec<String>
+}
+
+pub type ValveSet = HashMap<String, Valve>;
+
+/// Valve where both parts of the puzzle start.
+pub const START_VALVE: &str = "AA";
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Position {
+    pos_self: String,
+    pos_elephant: Option<String>
+}
+
+struct CurrentState {
+    opened_valves: BTreeSet<String>,
+    achieved_pressure_release: u32
+}
+
+/// Maps states to already released pressure: At each time for each puzzle state, we only have to keep the best path that is associated
+/// with the maximum achieved pressure release.
+type PuzzleState = HashMap<Position, Vec<CurrentState>>;
+
+/// Gives up with a TimedOut error (stating the minute and best pressure release reached so far) once the token is cancelled.
+pub fn calc_max_releasable_pressure(valves: &ValveSet, start_pos: &str, minutes: u32, include_elephant: bool,
+    cancel: &CancelToken) -> Result<u32, TimedOut> {
+    // Initialize structure to track all states that can be reached in each iteration
+    let mut states = PuzzleState::new();
+    states.insert(
+        Position {
+            pos_self: start_pos.into(),
+            pos_elephant: include_elephant.then_some(start_pos.into())
+        },
+        vec!(
+            CurrentState {
+                opened_valves: BTreeSet::new(),
+                achieved_pressure_release: 0
+            }
+        )
+    );
+
+    let mut max_achieavable_flow = 0;
+
+    for minute in 0..minutes {
+        let total_states_count: usize = states.values().map(|s| s.len()).sum();
+        debug!(minute, states = total_states_count, positions = states.len(), max_pressure_release = max_achieavable_flow,
+            "Tracking states");
+
+        // Loop over all current positions and states and generates states reachable from there in the next minute
+        let mut next_states = PuzzleState::new();
+
+        for (cur_pos, states_list) in states {
+            cancel.check(|| format!("in minute {} of {} with a pressure release of {} so far", minute + 1, minutes, max_achieavable_flow))?;
+
+            for state in states_list {
+                let pressure_released_this_minute: u32 = state.opened_valves
+                    .iter()
+                    .map(|v| valves.get(v).unwrap().flow_rate)
+                    .sum();
+                let total_pressure_released = state.achieved_pressure_release + pressure_released_this_minute;
+
+                // In the following nested two loops, a target of None means opening the valve at the current position
+                let empty_vec_dummy = [];  // ugly
+                let target_iter_self = valves.get(&cur_pos.pos_self).unwrap().tunnels_to.iter().map(Some);
+
+                for target_self in [None].into_iter().chain(target_iter_self) {
+                    let target_iter_elephant = if let Some(elephant_pos) = &cur_pos.pos_elephant {
+                        valves.get(&elephant_pos.clone()).unwrap().tunnels_to.iter().map(Some)
+                    } else {
+                        empty_vec_dummy.iter().map(Some)
+                    };
+
+                    for target_elephant in [None].into_iter().chain(target_iter_elephant) {
+                        let mut new_pos = cur_pos.clone();
+                        let mut opened_valves = state.opened_valves.clone();
+
+                        // Self: Move or open valve
+                        if let Some(tp) = target_self {
+                            new_pos.pos_self = tp.clone();
+                        } else {
+                            opened_valves.insert(cur_pos.pos_self.clone());
+                        }
+
+                        // Elephant: Move or open valve (if elephant exists)
+                        if let Some(tp) = target_elephant {
+                            new_pos.pos_elephant = Some(tp.clone());
+                        } else if let Some(elephant_pos) = &cur_pos.pos_elephant {
+                            opened_valves.insert(elephant_pos.clone());
+                        }
+
+                        track_successor_state(&mut next_states, new_pos, opened_valves, total_pressure_released);
+                    }
+                }
+            }
+        }
+
+        // Update states and calculate current best maximum achievable flow
+        states = next_states;
+        max_achieavable_flow = states.values().map(|s| s.iter().map(|t| t.achieved_pressure_release).max().unwrap()).max().unwrap();
+
+        // Starting in minute 6, we are using a heuristics to prune "bad" states to reduce the computational effort. We are pruning all
+        // states that have considerably less than the current best achievable flow. (This might make us miss the true solution though. In
+        // case that happens, this part has to be adjusted.)
+        if minute > 6 {
+            let prune_threshold = max_achieavable_flow.saturating_sub(30);
+            for states_list in states.values_mut() {
+                states_list.retain(|s| s.achieved_pressure_release >= prune_threshold);
+            }
+        }
+    }
+
+    // Return maximum achievable flow from best path
+    Ok(max_achieavable_flow)
+}
+
+pub fn parse_input(input: &str) -> Result<ValveSet, ParseError> {
+    let re = Regex::new(r"^Valve (?P<valve_code>[A-Z]{2}) has flow rate=(?P<flow_rate>\d+); tunnels? leads? to valves? (?P<tunnels_to>[A-Z ,]+)$").unwrap();
+
+    let mut res = ValveSet::new();
+    for (line_idx, l) in input.lines().enumerate() {
+        let caps = re.captures(l).ok_or_else(|| ParseError::in_line(line_idx, l, "Could not parse valve description!"))?;
+
+        // All capture groups are mandatory in the regex
+        let valve_code_match = caps.name("valve_code").unwrap();
+        let flow_rate_match = caps.name("flow_rate").unwrap();
+        let flow_rate = flow_rate_match
+            .as_str()
+            .parse()
+            .map_err(|_| ParseError::new(line_idx, flow_rate_match.start(), l, "Could not parse flow rate!"))?;
+        let tunnels_to = caps
+            .name("tunnels_to")
+            .unwrap()
+            .as_str()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .collect();
+
+        if res.insert(valve_code_match.as_str().to_string(), Valve { flow_rate, tunnels_to }).is_some() {
+            let message = format!("Valve {} is defined more than once!", valve_code_match.as_str());
+            return Err(ParseError::new(line_idx, valve_code_match.start(), l, message));
+        }
+    }
+
+    // The solver starts at this valve without checking it
+    if !res.contains_key(START_VALVE) {
+        return Err(ParseError::at_end(input, format!("Start valve {} not found!", START_VALVE)));
+    }
+
+    // Make sure that all tunnels lead to known valves (the solver looks them up without checking)
+    for (line_idx, l) in input.lines().enumerate() {
+        let tunnels_start = re.captures(l).unwrap().name("tunnels_to").unwrap().start();
+        for valve_code in l[tunnels_start..].split(',') {
+            if !res.contains_key(valve_code.trim()) {
+                let col_idx = tunnels_start + l[tunnels_start..].find(valve_code.trim()).unwrap();
+                return Err(ParseError::new(line_idx, col_idx, l, format!("Tunnel leads to unknown valve {}!", valve_code.trim())));
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<ValveSet> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+fn track_successor_state(states: &mut PuzzleState, cur_pos: Position, opened_valves: BTreeSet<String>, achieved_pressure_release: u32) {
+    if let Some(existing_states) = states.get_mut(&cur_pos) {
+        // Check if we already have a state that is strictly better (then we do not need to save this one)
+        for existing_state in existing_states.iter() {
+            if existing_state.opened_valves.is_superset(&opened_valves) &&
+                existing_state.achieved_pressure_release >= achieved_pressure_release {
+                return;
+            }
+        }
+
+        // Delete existing states that are strictly inferior and can be deleted
+        existing_states.retain(|possibly_delete_state| {
+            !opened_valves.is_superset(&possibly_delete_state.opened_valves) ||
+                achieved_pressure_release < possibly_delete_state.achieved_pressure_release
+        });
+
+        // Add new state
+        existing_states.push(CurrentState {
+            opened_valves,
+            achieved_pressure_release
+        })
+    } else {
+        states.insert(cur_pos, vec!(CurrentState {
+            opened_valves,
+            achieved_pressure_release
+        }));
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = ValveSet;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<ValveSet, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(valves: &ValveSet) -> Result<u32> {
+        Self::part1_cancellable(valves, &CancelToken::new())
+    }
+
+    fn part2(valves: &ValveSet) -> Result<u32> {
+        Self::part2_cancellable(valves, &CancelToken::new())
+    }
+
+    fn part1_cancellable(valves: &ValveSet, cancel: &CancelToken) -> Result<u32> {
+        Ok(calc_max_releasable_pressure(valves, START_VALVE, 30, false, cancel)?)
+    }
+
+    fn part2_cancellable(valves: &ValveSet, cancel: &CancelToken) -> Result<u32> {
+        Ok(calc_max_releasable_pressure(valves, START_VALVE, 26, true, cancel)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let valves = Day16::read_input("../inputs/day16_example.txt").unwrap();
+        assert_eq!(Day16::part1(&valves).unwrap(), 1651);
+    }
+
+    #[test]
+    fn example_part2() {
+        let valves = Day16::read_input("../inputs/day16_example.txt").unwrap();
+        assert_eq!(Day16::part2(&valves).unwrap(), 1707);
+    }
+
+    #[test]
+    fn low_pressure_release() {
+        // The pruning threshold must not underflow when less than 30 pressure has been released after the first minutes
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=1; tunnel leads to valve AA";
+        let valves = parse_input(input).unwrap();
+        assert_eq!(Day16::part1(&valves).unwrap(), 28);
+        assert_eq!(Day16::part1(&valves).unwrap(), reference::calc_max_releasable_pressure(&valves, "AA", 30, false));
+        assert_eq!(Day16::part2(&valves).unwrap(), 24);
+    }
+
+    #[test]
+    fn invalid_valves() {
+        assert_eq!(parse_input("").unwrap_err().message, "Start valve AA not found!");
+        assert_eq!(parse_input("Valve BB has flow rate=1; tunnel leads to valve BB\n").unwrap_err().message, "Start valve AA not found!");
+
+        let e = parse_input("Valve AA has flow rate=0; tunnel leads to valve AA\nValve AA has flow rate=1; tunnel leads to valve AA\n")
+            .unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 7, "Valve AA is defined more than once!"));
+    }
+
+    #[test]
+    fn cancelled() {
+        let valves = Day16::read_input("../inputs/day16_example.txt").unwrap();
+        let cancel = CancelToken::new();
+        cancel.cancel();
+
+        let err = calc_max_releasable_pressure(&valves, "AA", 30, false, &cancel).unwrap_err();
+        assert_eq!(err.progress, "in minute 1 of 30 with a pressure release of 0 so far");
+        assert!(Day16::part2_cancellable(&valves, &cancel).unwrap_err().is::<TimedOut>());
+    }
+}
@@ -0,0 +1,92 @@
+//! Slow but obviously correct reference solver (used to check the pruning heuristics of calc_max_releasable_pressure): Every order in
+//! which the valves with a flow rate can be opened is tried, walking between them on shortest paths.
+
+use crate::ValveSet;
+use std::cmp::max;
+use std::collections::{HashMap, VecDeque};
+
+/// Returns the number of minutes to walk from the given valve to each reachable valve.
+fn distances_from(valves: &ValveSet, start: &str) -> HashMap<String, u32> {
+    let mut distances = HashMap::from([(start.to_string(), 0)]);
+    let mut queue = VecDeque::from([start.to_string()]);
+
+    while let Some(valve) = queue.pop_front() {
+        let distance = distances[&valve];
+        for next in &valves[&valve].tunnels_to {
+            if !distances.contains_key(next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next.clone());
+            }
+        }
+    }
+
+    distances
+}
+
+/// Returns the best pressure release for each set of opened valves (bit i stands for the i-th valve with a flow rate).
+fn best_per_opened_set(valves: &ValveSet, start_pos: &str, minutes: u32) -> HashMap<u64, u32> {
+    let mut targets: Vec<&String> = valves.iter().filter(|(_, v)| v.flow_rate > 0).map(|(code, _)| code).collect();
+    targets.sort();
+    assert!(targets.len() <= 64, "Too many valves with a flow rate for the reference solver!");
+
+    let distances: HashMap<&str, HashMap<String, u32>> = targets
+        .iter()
+        .map(|t| t.as_str())
+        .chain([start_pos])
+        .map(|v| (v, distances_from(valves, v)))
+        .collect();
+
+    let mut best = HashMap::new();
+    let mut stack = vec!((start_pos, minutes, 0u64, 0u32));
+
+    while let Some((pos, minutes_left, opened, released)) = stack.pop() {
+        let best_for_set = best.entry(opened).or_insert(0);
+        *best_for_set = max(*best_for_set, released);
+
+        for (i, target) in targets.iter().enumerate() {
+            if opened & (1 << i) != 0 {
+                continue;
+            }
+
+            // Walking there and opening the valve has to leave at least one minute in which the valve releases pressure
+            if let Some(distance) = distances[pos].get(*target) {
+                if distance + 1 < minutes_left {
+                    let minutes_open = minutes_left - distance - 1;
+                    stack.push((target.as_str(), minutes_open, opened | (1 << i), released + minutes_open * valves[*target].flow_rate));
+                }
+            }
+        }
+    }
+
+    best
+}
+
+pub fn calc_max_releasable_pressure(valves: &ValveSet, start_pos: &str, minutes: u32, include_elephant: bool) -> u32 {
+    let best = best_per_opened_set(valves, start_pos, minutes);
+
+    if include_elephant {
+        // The elephant and we open disjoint sets of valves, so the best plan combines the best paths of two such sets
+        best.iter()
+            .flat_map(|(set_self, released_self)| {
+                best.iter().filter(move |(set_elephant, _)| *set_self & **set_elephant == 0).map(move |(_, r)| released_self + r)
+            })
+            .max()
+            .unwrap()
+    } else {
+        *best.values().max().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day16;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let valves = Day16::read_input("../inputs/day16_example.txt").unwrap();
+        assert_eq!(calc_max_releasable_pressure(&valves, "AA", 30, false), 1651);
+        assert_eq!(calc_max_releasable_pressure(&valves, "AA", 26, true), 1707);
+    }
+}
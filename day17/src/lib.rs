@@ -0,0 +1,288 @@
+use anyhow::{Context, Result};
+use common::{CancelToken, ParseError, Solution, TimedOut};
+use grid::{Pos, SparseGrid};
+use std::cmp::max;
+use std::collections::{BTreeSet, HashMap};
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{debug, enabled, trace, Level};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+pub mod visualization;
+
+/// All coordinates are one-indexed, i.e. the lowest row is y=1 and x is in [1, 7] (y grows upwards here, unlike in the other days)
+type Field = SparseGrid;
+
+const NUM_ROWS_STATE: u64 = 100;
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+enum BlockType {
+    HLine,
+    Plus,
+    Corner,
+    VLine,
+    Square
+}
+
+#[derive(Eq, Hash, PartialEq)]
+struct BlockFallResult {
+    block_type: BlockType,
+    push_dir_index: usize,
+    fallen_steps: u64,
+    top_rows_state: BTreeSet<Pos>  // Contains the state of the top NUM_ROWS_STATE rows
+}
+
+/// Data structure used to detect cycles for the second part of the puzzle (the value stores the round and the height)
+type CycleCheckingMap = HashMap<BlockFallResult, (u64, u64)>;
+
+/// Renders the chamber with the top row first.
+fn render_field(f: &Field) -> String {
+    let height = calc_height(f) as i32;
+    let rows = grid::render_area((1, 1), (7, height), |(x, y)| if f.contains((x, height + 1 - y)) { '#' } else { '.' });
+    let rendered: String = rows.lines().map(|row| format!("|{}|\n", row)).collect();
+    format!("{}+-------+", rendered)
+}
+
+/// Emits the current field as a trace event (only rendered if trace events are enabled).
+fn trace_field(f: &Field) {
+    if enabled!(Level::TRACE) {
+        trace!("Current field is:\n{}", render_field(f));
+    }
+}
+
+fn calc_height(f: &Field) -> u64 {
+    f.positions().map(|(_, y)| y as u64).max().unwrap_or(0)
+}
+
+/// Without a cycle, the number of rounds of the second part cannot be simulated: The token stops the simulation with a TimedOut error
+/// stating the round and height reached so far.
+pub fn calc_height_after_rounds(push_directions: &[i8], num_rounds: u64, cancel: &CancelToken) -> Result<u64, TimedOut> {
+    let mut f = Field::new();
+    let mut push_dir_counter = 0;
+    let mut known_states = CycleCheckingMap::new();
+    let mut round = 0;
+    let mut cycle_height_adder = None;
+
+    while round < num_rounds {
+        let cur_height = calc_height(&f);
+        trace!(round, cur_height, ?cycle_height_adder);
+        cancel.check(|| {
+            let cycle = if cycle_height_adder.is_some() { "after applying a cycle" } else { "without finding a cycle" };
+            format!("in round {} of {} at a height of {} {}", round + 1, num_rounds, cur_height + cycle_height_adder.unwrap_or(0), cycle)
+        })?;
+
+        let block_type = get_block_type(round);
+        let spawn_pos = (3, cur_height as i32 + 4);
+        let fall_counter = simulate_block_fall(push_directions, &mut f, &mut push_dir_counter, &block_type, spawn_pos);
+        trace_field(&f);
+
+        // Check for cycles
+        let top_rows_base_y = cur_height.saturating_sub(NUM_ROWS_STATE) as i32;
+        let top_rows_state = f
+            .positions()
+            .filter_map(|(x, y)| {
+                if y >= top_rows_base_y {
+                    Some((x, y - top_rows_base_y))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let bfr = BlockFallResult {
+            block_type,
+            push_dir_index: push_dir_counter.rem_euclid(push_directions.len()),
+            fallen_steps: fall_counter,
+            top_rows_state
+        };
+
+        if cycle_height_adder.is_none() {
+            if let Some((last_round, last_height)) = known_states.get(&bfr) {
+                let cycle_blocks = round - last_round;
+                let cycle_height_increase = cur_height - last_height;
+                debug!(cycle_blocks, cycle_height_increase, block_type = ?bfr.block_type, push_dir_index = bfr.push_dir_index,
+                    fallen_steps = bfr.fallen_steps, top_rows_fields = bfr.top_rows_state.len(), "Cycle found");
+
+                // Fast-forward by applying the cycle to skip computational effort
+                let apply_cycles = max((num_rounds - round) / cycle_blocks, 1) - 1;
+                cycle_height_adder = Some(apply_cycles * cycle_height_increase);
+                round += apply_cycles * cycle_blocks;
+
+                debug!(apply_cycles, added_height = cycle_height_adder.unwrap(), "Applying cycles to save computational effort");
+            } else {
+                known_states.insert(bfr, (round, cur_height));
+            }
+        }
+
+        round += 1;
+    }
+
+    Ok(calc_height(&f) + cycle_height_adder.unwrap_or(0))
+}
+
+fn check_block_collision(f: &mut Field, block_type: &BlockType, block_pos: Pos) -> bool {
+    for offset in get_block_fields(block_type) {
+        if f.contains((block_pos.0 + offset.0, block_pos.1 + offset.1)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns the type of the block falling in the given (zero-based) round.
+fn get_block_type(round: u64) -> BlockType {
+    match round.rem_euclid(5) {
+        0 => BlockType::HLine,
+        1 => BlockType::Plus,
+        2 => BlockType::Corner,
+        3 => BlockType::VLine,
+        4 => BlockType::Square,
+        _ => panic!("mod 5 not in 0..=4?!")
+    }
+}
+
+fn get_block_fields(block_type: &BlockType) -> Vec<Pos> {
+    match block_type {
+        BlockType::HLine => vec!((0, 0), (1, 0), (2, 0), (3, 0)),
+        BlockType::Plus => vec!((0, 1), (1, 0), (1, 1), (1, 2), (2, 1)),
+        BlockType::Corner => vec!((0, 0), (1, 0), (2, 0), (2, 1), (2, 2)),
+        BlockType::VLine => vec!((0, 0), (0, 1), (0, 2), (0, 3)),
+        BlockType::Square => vec!((0, 0), (1, 0), (0, 1), (1, 1))
+    }
+}
+
+fn get_block_width(block_type: &BlockType) -> i32 {
+    match block_type {
+        BlockType::HLine => 4,
+        BlockType::Plus => 3,
+        BlockType::Corner => 3,
+        BlockType::VLine => 1,
+        BlockType::Square => 2
+    }
+}
+
+/// Parses the jet pattern (line breaks are ignored, so it may also be split over several lines).
+pub fn parse_input(input: &str) -> Result<Vec<i8>, ParseError> {
+    let mut res = vec!();
+    for (line_idx, line) in input.lines().enumerate() {
+        for (col_idx, c) in line.chars().enumerate() {
+            res.push(match c {
+                '<' => -1,
+                '>' => 1,
+                _ => return Err(ParseError::new(line_idx, col_idx, line, format!("Unknown jet direction: {}", c)))
+            });
+        }
+    }
+
+    if res.is_empty() {
+        return Err(ParseError::at_end(input, "Jet pattern is empty!"));
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<i8>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+/// Returns the number of fields the block has fallen (used for detecting cycles for the second half of the puzzle).
+fn simulate_block_fall(push_directions: &[i8], f: &mut Field, push_dir_counter: &mut usize, block_type: &BlockType,
+    spawn_pos: Pos) -> u64 {
+    let mut cur_pos = spawn_pos;
+    let mut fall_counter = 0;
+
+    loop {
+        // Push horizontally
+        let push_dir = push_directions.get(push_dir_counter.rem_euclid(push_directions.len())).unwrap();
+        let push_pos = (cur_pos.0 + *push_dir as i32, cur_pos.1);
+        *push_dir_counter += 1;
+
+        if push_pos.0 >= 1 && push_pos.0 + get_block_width(block_type) <= 8 && !check_block_collision(f, block_type, push_pos) {
+            cur_pos = push_pos;
+        }
+
+        // Fall down vertically
+        let falldown_pos = (cur_pos.0, cur_pos.1 - 1);
+        if falldown_pos.1 == 0 || check_block_collision(f, block_type, falldown_pos) {
+            break;
+        }
+        cur_pos = falldown_pos;
+        fall_counter += 1;
+    }
+
+    // Block has collided, at positions to field
+    for offset in get_block_fields(block_type) {
+        if f.insert((cur_pos.0 + offset.0, cur_pos.1 + offset.1), ()).is_some() {
+            panic!("Attempted to set a position on the field that was already occupied! (should never happen)")
+        }
+    }
+
+    fall_counter
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<i8>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<i8>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(push_directions: &Vec<i8>) -> Result<u64> {
+        Self::part1_cancellable(push_directions, &CancelToken::new())
+    }
+
+    fn part2(push_directions: &Vec<i8>) -> Result<u64> {
+        Self::part2_cancellable(push_directions, &CancelToken::new())
+    }
+
+    fn part1_cancellable(push_directions: &Vec<i8>, cancel: &CancelToken) -> Result<u64> {
+        Ok(calc_height_after_rounds(push_directions, 2022, cancel)?)
+    }
+
+    fn part2_cancellable(push_directions: &Vec<i8>, cancel: &CancelToken) -> Result<u64> {
+        Ok(calc_height_after_rounds(push_directions, 1000000000000, cancel)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let push_directions = Day17::read_input("../inputs/day17_example.txt").unwrap();
+        assert_eq!(Day17::part1(&push_directions).unwrap(), 3068);
+    }
+
+    #[test]
+    fn example_part2() {
+        let push_directions = Day17::read_input("../inputs/day17_example.txt").unwrap();
+        assert_eq!(Day17::part2(&push_directions).unwrap(), 1514285714288);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let e = parse_input("<<>\n<x>\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 2, "Unknown jet direction: x"));
+
+        assert_eq!(parse_input("").unwrap_err().message, "Jet pattern is empty!");
+        assert_eq!(parse_input("\n").unwrap_err().message, "Jet pattern is empty!");
+    }
+
+    #[test]
+    fn cancelled() {
+        let push_directions = Day17::read_input("../inputs/day17_example.txt").unwrap();
+        let cancel = CancelToken::new();
+        cancel.cancel();
+
+        let err = calc_height_after_rounds(&push_directions, 2022, &cancel).unwrap_err();
+        assert_eq!(err.progress, "in round 1 of 2022 at a height of 0 without finding a cycle");
+    }
+}
@@ -0,0 +1,64 @@
+//! Slow but obviously correct reference solver (used to check the cycle detection of calc_height_after_rounds): Every single block is
+//! simulated, so this is only feasible for a moderate number of rounds.
+
+/// Fields of each block relative to its lower left corner, in the order in which the blocks fall.
+const BLOCKS: [&[(usize, usize)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)]
+];
+
+/// Checks whether the block fits into the chamber at the given position (x is zero-based from the left wall, y from the floor).
+fn fits(rows: &[[bool; 7]], block: &[(usize, usize)], x: i64, y: i64) -> bool {
+    block.iter().all(|(dx, dy)| {
+        let (bx, by) = (x + *dx as i64, y + *dy as i64);
+        (0..7).contains(&bx) && by >= 0 && !rows.get(by as usize).is_some_and(|row| row[bx as usize])
+    })
+}
+
+pub fn calc_height_after_rounds(push_directions: &[i8], num_rounds: u64) -> u64 {
+    let mut rows: Vec<[bool; 7]> = vec!();
+    let mut jets = push_directions.iter().cycle();
+
+    for round in 0..num_rounds {
+        let block = BLOCKS[(round % 5) as usize];
+        let (mut x, mut y) = (2, rows.len() as i64 + 3);
+
+        loop {
+            let pushed_x = x + *jets.next().unwrap() as i64;
+            if fits(&rows, block, pushed_x, y) {
+                x = pushed_x;
+            }
+
+            if !fits(&rows, block, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (dx, dy) in block {
+            let (bx, by) = ((x as usize) + dx, (y as usize) + dy);
+            if by >= rows.len() {
+                rows.resize(by + 1, [false; 7]);
+            }
+            rows[by][bx] = true;
+        }
+    }
+
+    rows.len() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let push_directions = Day17::read_input("../inputs/day17_example.txt").unwrap();
+        assert_eq!(calc_height_after_rounds(&push_directions, 2022), 3068);
+    }
+}
@@ -0,0 +1,60 @@
+//! Frame-by-frame visualization of the falling rocks, where every frame adds one rock to the chamber.
+
+use crate::{calc_height, get_block_type, render_field, simulate_block_fall, Field};
+use common::Visualization;
+
+#[derive(Clone)]
+pub struct RockVisualization<'a> {
+    push_directions: &'a [i8],
+    num_rounds: u64,
+    field: Field,
+    push_dir_counter: usize,
+    round: u64
+}
+
+impl<'a> RockVisualization<'a> {
+    pub fn new(push_directions: &'a [i8], num_rounds: u64) -> Self {
+        RockVisualization { push_directions, num_rounds, field: Field::new(), push_dir_counter: 0, round: 0 }
+    }
+}
+
+impl Visualization for RockVisualization<'_> {
+    fn step(&mut self) -> bool {
+        if self.round >= self.num_rounds {
+            return false;
+        }
+
+        let spawn_pos = (3, calc_height(&self.field) as i32 + 4);
+        simulate_block_fall(self.push_directions, &mut self.field, &mut self.push_dir_counter, &get_block_type(self.round), spawn_pos);
+        self.round += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        render_field(&self.field)
+    }
+
+    fn status(&self) -> String {
+        format!("{} of {} rocks fallen, height {}", self.round, self.num_rounds, calc_height(&self.field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let push_directions = Day17::read_input("../inputs/day17_example.txt").unwrap();
+        let mut v = RockVisualization::new(&push_directions, 2022);
+
+        assert!(v.step());
+        assert_eq!(v.render(), "|..####.|\n+-------+");
+
+        while v.step() {}
+        assert_eq!(calc_height(&v.field), 3068);
+        assert_eq!(v.status(), "2022 of 2022 rocks fallen, height 3068");
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use std::collections::HashSet;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub type CubePos = (i8, i8, i8);
+
+const CUBE_OFFSETS: [(i8, i8, i8); 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1)
+];
+
+/// Returns the neighbor in the direction of the offset (None if it lies outside the coordinate range).
+fn get_neighbor(c: &CubePos, offset: &(i8, i8, i8)) -> Option<CubePos> {
+    Some((c.0.checked_add(offset.0)?, c.1.checked_add(offset.1)?, c.2.checked_add(offset.2)?))
+}
+
+fn calc_outside_air_cubes(lava_cubes: &HashSet<CubePos>, visited_air_cubes: &mut HashSet<CubePos>, min_coords: &CubePos,
+    max_coords: &CubePos, cur_coord: CubePos) {
+    // Abort if current position is outside of the min/max coordinates to limit recursion
+    if cur_coord.0 < min_coords.0 || cur_coord.1 < min_coords.1 || cur_coord.2 < min_coords.2 ||
+        cur_coord.0 > max_coords.0 || cur_coord.1 > max_coords.1 || cur_coord.2 > max_coords.2 {
+        return;
+    }
+
+    // Only recurse if the current position is neither lava nor a before visited air cube
+    if !lava_cubes.contains(&cur_coord) && visited_air_cubes.insert(cur_coord) {
+        for offset in CUBE_OFFSETS {
+            calc_outside_air_cubes(lava_cubes, visited_air_cubes, min_coords, max_coords,
+                (cur_coord.0 + offset.0, cur_coord.1 + offset.1, cur_coord.2 + offset.2))
+        }
+    }
+}
+
+/// The second parameter is used only during second part of the puzzle.
+pub fn calc_surface_area_part1(cubes: &HashSet<CubePos>, outside_air_cubes: Option<&HashSet<CubePos>> ) -> usize {
+    let mut surface_count = 0;
+
+    for c in cubes {
+        for offset in CUBE_OFFSETS {
+            // Neighbors outside the coordinate range can only be outside air
+            let Some(neighbor) = get_neighbor(c, &offset) else {
+                surface_count += 1;
+                continue;
+            };
+            if !cubes.contains(&neighbor) && outside_air_cubes.map(|oac| oac.contains(&neighbor)).unwrap_or(true) {
+                surface_count += 1;
+            }
+        }
+    }
+
+    surface_count
+}
+
+/// For the second part of the puzzle (considering only the real surface area without counting cubes of trapped air), we have to be a bit
+/// more sophisticated. Here, we are first using a BFS to determine which (air) cubes are actually part of the "outside air".
+pub fn calc_surface_area_part2(cubes: &HashSet<CubePos>) -> Result<usize> {
+    // Determine min and max coordinates for the BFS (min/max may be different on each axis), the air around the cubes must be
+    // representable
+    let axes: [fn(&CubePos) -> i8; 3] = [|c| c.0, |c| c.1, |c| c.2];
+    let mut bounds = vec!();
+    for axis in axes {
+        let min = cubes.iter().map(axis).min().context("No cubes given!")?;
+        let max = cubes.iter().map(axis).max().context("No cubes given!")?;
+        bounds.push(min.checked_sub(1).zip(max.checked_add(1)).with_context(|| {
+            format!("Coordinates must lie between {} and {} to leave room for the outside air!", i8::MIN + 1, i8::MAX - 1)
+        })?);
+    }
+    let min_coords = (bounds[0].0, bounds[1].0, bounds[2].0);
+    let max_coords = (bounds[0].1, bounds[1].1, bounds[2].1);
+
+    let mut outside_air = HashSet::new();
+    calc_outside_air_cubes(cubes, &mut outside_air, &min_coords, &max_coords, min_coords);
+
+    Ok(calc_surface_area_part1(cubes, Some(&outside_air)))
+}
+
+pub fn parse_input(input: &str) -> Result<HashSet<CubePos>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| {
+            let mut col_idx = 0;
+            let mut coords = vec!();
+
+            for s in l.split(',') {
+                coords.push(s.parse::<i8>().map_err(|_| ParseError::new(line_idx, col_idx, l, format!("Could not parse \"{}\" as number!", s)))?);
+                col_idx += s.len() + 1;
+            }
+
+            if coords.len() == 3 {
+                Ok((coords[0], coords[1], coords[2]))
+            } else {
+                Err(ParseError::in_line(line_idx, l, "Three-dimensional coordinates expected!"))
+            }
+        })
+        .collect()
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<HashSet<CubePos>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<CubePos>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<HashSet<CubePos>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cubes: &HashSet<CubePos>) -> Result<usize> {
+        Ok(calc_surface_area_part1(cubes, None))
+    }
+
+    fn part2(cubes: &HashSet<CubePos>) -> Result<usize> {
+        calc_surface_area_part2(cubes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let cubes = Day18::read_input("../inputs/day18_example.txt").unwrap();
+        assert_eq!(Day18::part1(&cubes).unwrap(), 64);
+        assert_eq!(Day18::part2(&cubes).unwrap(), 58);
+    }
+
+    #[test]
+    fn coordinate_range() {
+        let cubes = parse_input("").unwrap();
+        assert_eq!(Day18::part1(&cubes).unwrap(), 0);
+        assert_eq!(Day18::part2(&cubes).unwrap_err().to_string(), "No cubes given!");
+
+        let cubes = parse_input("127,0,0\n").unwrap();
+        assert_eq!(Day18::part1(&cubes).unwrap(), 6);
+        assert_eq!(Day18::part2(&cubes).unwrap_err().to_string(),
+            "Coordinates must lie between -127 and 126 to leave room for the outside air!");
+    }
+}
@@ -0,0 +1,327 @@
+use anyhow::{Context, Result};
+use common::{CancelToken, ParseError, Solution, TimedOut};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use regex::Regex;
+use std::cmp::max;
+use std::fs::read_to_string;
+use std::ops::{Add, Sub};
+use std::path::Path;
+use tracing::{debug, debug_span};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[derive(Clone, Debug)]
+pub struct ResourceList {
+    ore: i32,
+    clay: i32,
+    obsidian: i32,
+    geode: i32
+}
+
+impl Add for &ResourceList {
+    type Output = ResourceList;
+
+    fn add(self, other: Self) -> ResourceList {
+        ResourceList {
+            ore: self.ore + other.ore,
+            clay: self.clay + other.clay,
+            obsidian: self.obsidian + other.obsidian,
+            geode: self.geode + other.geode,
+        }
+    }
+}
+
+impl Sub for &ResourceList {
+    type Output = ResourceList;
+
+    fn sub(self, other: Self) -> ResourceList {
+        ResourceList {
+            ore: self.ore - other.ore,
+            clay: self.clay - other.clay,
+            obsidian: self.obsidian - other.obsidian,
+            geode: self.geode - other.geode,
+        }
+    }
+}
+
+impl ResourceList {
+    fn contains(&self, other: &Self) -> bool {
+        self.ore >= other.ore && self.clay >= other.clay && self.obsidian >= other.obsidian && self.geode >= other.geode
+    }
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    ore_robot_cost: ResourceList,
+    clay_robot_cost: ResourceList,
+    obsidian_robot_cost: ResourceList,
+    geode_robot_cost: ResourceList,
+}
+
+/// Number of visited states after which the search checks whether it has been cancelled.
+const CANCEL_CHECK_INTERVAL: u64 = 1 << 16;
+
+/// State shared by all branches of the search for one blueprint.
+struct SearchState<'a> {
+    global_max_seen_final_geode_count: i32,  // Used internally for pruning
+    visited_states: u64,
+    cancel: &'a CancelToken
+}
+
+/// Gives up with a TimedOut error (stating the number of geodes that can at least be opened) once the token is cancelled.
+pub fn get_max_geode_count(bp: &Blueprint, time_minutes: u8, cancel: &CancelToken) -> Result<i32, TimedOut> {
+    let mut search = SearchState { global_max_seen_final_geode_count: 0, visited_states: 0, cancel };
+
+    // We are limiting the number of robots based on the blueprint: It does not make sense to have more robots of a specific kind (except
+    // geode robots) than are necessary to produce the maximum that can be spend by any robot construction recipe.
+    let robot_limits_for_blueprint = ResourceList {
+        ore: max(max(bp.ore_robot_cost.ore, bp.clay_robot_cost.ore),
+            max(bp.obsidian_robot_cost.ore, bp.geode_robot_cost.ore)),
+        clay: max(max(bp.ore_robot_cost.clay, bp.clay_robot_cost.clay),
+            max(bp.obsidian_robot_cost.clay, bp.geode_robot_cost.clay)),
+        obsidian: max(max(bp.ore_robot_cost.obsidian, bp.clay_robot_cost.obsidian),
+            max(bp.obsidian_robot_cost.obsidian, bp.geode_robot_cost.obsidian)),
+        geode: 0  // not used
+    };
+
+    get_max_geode_count_recurse(
+        bp,
+        &robot_limits_for_blueprint,
+        time_minutes,
+        ResourceList {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0
+        },
+        ResourceList {
+            ore: 1,
+            clay: 0,
+            obsidian: 0,
+            geode: 0
+        },
+        &mut search
+    )
+}
+
+/// This function recurses into possible option for this turn, but also includes some pruning to make the problem tractable. Regarding the
+/// robot limits, see comment in get_max_geode_count().
+fn get_max_geode_count_recurse(bp: &Blueprint, robot_limits_for_blueprint: &ResourceList, minutes_left: u8, cur_resources: ResourceList,
+    cur_robots: ResourceList, search: &mut SearchState) -> Result<i32, TimedOut> {
+    search.visited_states += 1;
+    if search.visited_states.is_multiple_of(CANCEL_CHECK_INTERVAL) {
+        search.cancel.check(|| format!("after {} states at least {} geodes can be opened", search.visited_states,
+            search.global_max_seen_final_geode_count))?;
+    }
+
+    if minutes_left == 0 {
+        Ok(cur_resources.geode)
+    } else {
+        let next_resources = &cur_resources + &cur_robots;
+
+        let mut best = -1;
+
+        // Calculate how many final geodes this state will generate if we would be building no more geode robots or if we would be building
+        // only geode robots from now on (which is the most optimistic possible case to maximize the final geode number)
+        let minutes_left_m1 = minutes_left as i32 - 1;
+        let this_state_final_geode_count_most_pessimistic =
+            cur_resources.geode +  // geodes that are already there
+            minutes_left as i32 * cur_robots.geode;  // geodes produces by the robots that are already there
+        let this_state_final_geode_count_most_optimistic =
+            this_state_final_geode_count_most_pessimistic +
+            ((minutes_left_m1 * minutes_left_m1) + minutes_left_m1) / 2;  // geodes produces by new robots, sum formula with N = (minutes_left - 1)
+
+        if this_state_final_geode_count_most_optimistic < search.global_max_seen_final_geode_count {
+            // This state can no longer help us reach a new maximum
+            return Ok(-1);
+        } else if this_state_final_geode_count_most_pessimistic > search.global_max_seen_final_geode_count {
+            search.global_max_seen_final_geode_count = this_state_final_geode_count_most_pessimistic
+        };
+        
+        // Build geode robot (there is no limit for geode robots)
+        if cur_resources.contains(&bp.geode_robot_cost) {
+            best = max(best, get_max_geode_count_recurse(
+                bp,
+                robot_limits_for_blueprint,
+                minutes_left - 1,
+                &next_resources - &bp.geode_robot_cost,
+                &cur_robots + &ResourceList { ore: 0, clay: 0, obsidian: 0, geode: 1 },
+                search
+            )?);
+        }
+
+        // Build obsidian robot
+        if cur_robots.obsidian < robot_limits_for_blueprint.obsidian && cur_resources.contains(&bp.obsidian_robot_cost) {
+            best = max(best, get_max_geode_count_recurse(
+                bp,
+                robot_limits_for_blueprint,
+                minutes_left - 1,
+                &next_resources - &bp.obsidian_robot_cost,
+                &cur_robots + &ResourceList { ore: 0, clay: 0, obsidian: 1, geode: 0 },
+                search
+            )?);
+        }
+
+        // Build clay robot
+        if cur_robots.clay < robot_limits_for_blueprint.clay && cur_resources.contains(&bp.clay_robot_cost) {
+            best = max(best, get_max_geode_count_recurse(
+                bp,
+                robot_limits_for_blueprint,
+                minutes_left - 1,
+                &next_resources - &bp.clay_robot_cost,
+                &cur_robots + &ResourceList { ore: 0, clay: 1, obsidian: 0, geode: 0 },
+                search
+            )?);
+        }
+
+        // Build ore robot
+        if cur_robots.ore < robot_limits_for_blueprint.ore && cur_resources.contains(&bp.ore_robot_cost) {
+            best = max(best, get_max_geode_count_recurse(
+                bp,
+                robot_limits_for_blueprint,
+                minutes_left - 1,
+                &next_resources - &bp.ore_robot_cost,
+                &cur_robots + &ResourceList { ore: 1, clay: 0, obsidian: 0, geode: 0 },
+                search
+            )?);
+        }
+
+        // Do nothing and wait
+        best = max(best, get_max_geode_count_recurse(
+            bp,
+            robot_limits_for_blueprint,
+            minutes_left - 1,
+            &cur_resources + &cur_robots,
+            cur_robots,
+            search
+        )?);
+
+        Ok(best)
+    }
+}
+
+/// Multiplies the maximum number of geodes that can be opened with each of the first blueprints (second part of the puzzle).
+pub fn get_max_geode_count_product(bps: &[Blueprint], blueprint_count: usize, time_minutes: u8, cancel: &CancelToken)
+    -> Result<i32, TimedOut> {
+    let bps = &bps[..blueprint_count.min(bps.len())];
+
+    #[cfg(feature = "parallel")]
+    let bps = bps.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let bps = bps.iter();
+
+    bps.enumerate().map(|(i, bp)| get_max_geode_count(bp, time_minutes, cancel).map_err(|e| in_blueprint(i + 1, e))).product()
+}
+
+/// Adds the blueprint to the progress of a timed out search.
+fn in_blueprint(id: usize, e: TimedOut) -> TimedOut {
+    TimedOut { progress: format!("blueprint {}: {}", id, e.progress) }
+}
+
+pub fn get_quality_level_sum(bps: &[Blueprint], time_minutes: u8, cancel: &CancelToken) -> Result<i32, TimedOut> {
+    // The blueprints are independent of each other, so they can be evaluated in parallel
+    #[cfg(feature = "parallel")]
+    let bps = bps.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let bps = bps.iter();
+
+    bps.enumerate().map(|(i, bp)| {
+        let geodes = debug_span!("blueprint", id = i + 1).in_scope(|| get_max_geode_count(bp, time_minutes, cancel));
+        let geodes = geodes.map_err(|e| in_blueprint(i + 1, e))?;
+        debug!(blueprint = i + 1, geodes, "Evaluated blueprint");
+        Ok((i + 1) as i32 * geodes)
+    }).sum()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let re = Regex::new(r"^Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.$").unwrap();
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(num, l)| {
+            let caps = re.captures(l).ok_or_else(|| ParseError::in_line(num, l, "Could not parse blueprint description!"))?;
+            let numbers: Vec<i32> = (1..=7).map(|i| {
+                let m = caps.get(i).unwrap();  // All capture groups are mandatory in the regex
+                m.as_str().parse().map_err(|_| ParseError::new(num, m.start(), l, "Could not parse number!"))
+            }).collect::<Result<_, _>>()?;
+
+            if numbers[0] as usize != (num + 1) {
+                return Err(ParseError::new(num, 10, l, format!("Unexpected number of blueprint (expected {})!", num + 1)));
+            }
+
+            Ok(Blueprint {
+                ore_robot_cost: ResourceList { ore: numbers[1], clay: 0, obsidian: 0, geode: 0 },
+                clay_robot_cost: ResourceList { ore: numbers[2], clay: 0, obsidian: 0, geode: 0 },
+                obsidian_robot_cost: ResourceList { ore: numbers[3], clay: numbers[4], obsidian: 0, geode: 0 },
+                geode_robot_cost: ResourceList { ore: numbers[5], clay: 0, obsidian: numbers[6], geode: 0 },
+            })
+        })
+        .collect()
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Blueprint>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(blueprints: &Vec<Blueprint>) -> Result<i32> {
+        Self::part1_cancellable(blueprints, &CancelToken::new())
+    }
+
+    fn part2(blueprints: &Vec<Blueprint>) -> Result<i32> {
+        Self::part2_cancellable(blueprints, &CancelToken::new())
+    }
+
+    fn part1_cancellable(blueprints: &Vec<Blueprint>, cancel: &CancelToken) -> Result<i32> {
+        Ok(get_quality_level_sum(blueprints, 24, cancel)?)
+    }
+
+    fn part2_cancellable(blueprints: &Vec<Blueprint>, cancel: &CancelToken) -> Result<i32> {
+        Ok(get_max_geode_count_product(blueprints, 3, 32, cancel)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let blueprints = Day19::read_input("../inputs/day19_example.txt").unwrap();
+        assert_eq!(get_max_geode_count(&blueprints[0], 24, &CancelToken::new()).unwrap(), 9);
+        assert_eq!(get_max_geode_count(&blueprints[1], 24, &CancelToken::new()).unwrap(), 12);
+        assert_eq!(Day19::part1(&blueprints).unwrap(), 33);
+    }
+
+    #[test]
+    fn example_part2() {
+        let blueprints = Day19::read_input("../inputs/day19_example.txt").unwrap();
+        assert_eq!(get_max_geode_count(&blueprints[0], 32, &CancelToken::new()).unwrap(), 56);
+        assert_eq!(get_max_geode_count(&blueprints[1], 32, &CancelToken::new()).unwrap(), 62);
+        assert_eq!(Day19::part2(&blueprints).unwrap(), 56 * 62);
+    }
+
+    #[test]
+    fn cancelled() {
+        let blueprints = Day19::read_input("../inputs/day19_example.txt").unwrap();
+        let cancel = CancelToken::new();
+        cancel.cancel();
+
+        let err = get_quality_level_sum(&blueprints, 24, &cancel).unwrap_err();
+        assert!(err.progress.starts_with("blueprint 1: after 65536 states at least "), "{}", err.progress);
+    }
+}
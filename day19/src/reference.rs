@@ -0,0 +1,72 @@
+//! Slow but obviously correct reference solver (used to check the pruning of get_max_geode_count): For every state, each kind of robot
+//! is tried as the next one to build (waiting until it can be afforded), without any bound on the achievable geodes. The only restriction
+//! is that there are never more robots of a kind (except geode robots) than resources of that kind can be spent in a minute, since only
+//! one robot can be built per minute.
+
+use crate::{Blueprint, ResourceList};
+use std::cmp::max;
+
+fn max_geodes_recurse(bp: &Blueprint, limits: &ResourceList, minutes_left: i32, resources: &ResourceList, robots: &ResourceList) -> i32 {
+    // Option: Build nothing anymore and just collect until the time is up
+    let mut best = resources.geode + minutes_left * robots.geode;
+
+    let options = [
+        (&bp.ore_robot_cost, ResourceList { ore: 1, clay: 0, obsidian: 0, geode: 0 }),
+        (&bp.clay_robot_cost, ResourceList { ore: 0, clay: 1, obsidian: 0, geode: 0 }),
+        (&bp.obsidian_robot_cost, ResourceList { ore: 0, clay: 0, obsidian: 1, geode: 0 }),
+        (&bp.geode_robot_cost, ResourceList { ore: 0, clay: 0, obsidian: 0, geode: 1 })
+    ];
+
+    for (cost, new_robot) in options {
+        if !limits.contains(&(robots + &new_robot)) {
+            continue;
+        }
+
+        // Wait until the robot can be afforded, then spend one more minute building it
+        let mut resources = resources.clone();
+        let mut minutes_left = minutes_left;
+        while minutes_left > 0 && !resources.contains(cost) {
+            resources = &resources + robots;
+            minutes_left -= 1;
+        }
+
+        if minutes_left > 0 {
+            let resources = &(&resources + robots) - cost;
+            best = max(best, max_geodes_recurse(bp, limits, minutes_left - 1, &resources, &(robots + &new_robot)));
+        }
+    }
+
+    best
+}
+
+pub fn get_max_geode_count(bp: &Blueprint, time_minutes: u8) -> i32 {
+    let costs = [&bp.ore_robot_cost, &bp.clay_robot_cost, &bp.obsidian_robot_cost, &bp.geode_robot_cost];
+    let limits = ResourceList {
+        ore: costs.iter().map(|c| c.ore).max().unwrap(),
+        clay: costs.iter().map(|c| c.clay).max().unwrap(),
+        obsidian: costs.iter().map(|c| c.obsidian).max().unwrap(),
+        geode: i32::MAX
+    };
+
+    max_geodes_recurse(
+        bp,
+        &limits,
+        time_minutes as i32,
+        &ResourceList { ore: 0, clay: 0, obsidian: 0, geode: 0 },
+        &ResourceList { ore: 1, clay: 0, obsidian: 0, geode: 0 }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day19;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let blueprints = Day19::read_input("../inputs/day19_example.txt").unwrap();
+        assert_eq!(get_max_geode_count(&blueprints[0], 24), 9);
+        assert_eq!(get_max_geode_count(&blueprints[1], 24), 12);
+    }
+}
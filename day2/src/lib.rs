@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub mod optimizer;
+pub mod rules;
+
+pub use rules::{HandChoice, Outcome, RuleSet};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match {
+    pub opponent_choice: HandChoice,
+    pub own_choice: HandChoice
+}
+
+/// Returns the two symbols of a line of the strategy guide.
+fn split_input_line(line_idx: usize, line: &str) -> Result<(char, char), ParseError> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() != 3 || chars[1] != ' ' {
+        return Err(ParseError::in_line(line_idx, line, "Input line must consist of two symbols separated by a space!"));
+    }
+    Ok((chars[0], chars[2]))
+}
+
+fn invalid_symbol(line_idx: usize, col_idx: usize, line: &str, symbol: char) -> ParseError {
+    ParseError::new(line_idx, col_idx, line, format!("Invalid hand symbol: {}", symbol))
+}
+
+impl Match {
+    fn decode_input_line(rules: &RuleSet, line_idx: usize, line: &str, second_half_of_puzzle: bool) -> Result<Self, ParseError> {
+        let (opponent_symbol, own_symbol) = split_input_line(line_idx, line)?;
+        let opponent_choice = rules
+            .decode_opponent_symbol(opponent_symbol)
+            .ok_or_else(|| invalid_symbol(line_idx, 0, line, opponent_symbol))?;
+        let own_choice = if second_half_of_puzzle {
+            // The own hand has to lead to the given outcome
+            let outcome = Outcome::decode_char(own_symbol).ok_or_else(|| invalid_symbol(line_idx, 2, line, own_symbol))?;
+            rules.choose_hand(opponent_choice, outcome)
+        } else {
+            rules.decode_own_symbol(own_symbol).ok_or_else(|| invalid_symbol(line_idx, 2, line, own_symbol))?
+        };
+
+        Ok(Match { opponent_choice, own_choice })
+    }
+
+    pub fn get_match_points(&self, rules: &RuleSet) -> usize {
+        rules.get_outcome(self.own_choice, self.opponent_choice).get_points()
+    }
+}
+
+/// If second_half_of_puzzle is true, the second symbol does not represent the own hand, but the desired outcome of the match
+/// (the own hand symbol must then be determined from the opponent's hand and the desired outcome)
+pub fn parse_matches(input: &str, rules: &RuleSet, second_half_of_puzzle: bool) -> Result<Vec<Match>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| Match::decode_input_line(rules, line_idx, l, second_half_of_puzzle))
+        .collect()
+}
+
+pub fn get_matches_from_input<P: AsRef<Path>>(input_path: P, rules: &RuleSet, second_half_of_puzzle: bool) -> Result<Vec<Match>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_matches(&input, rules, second_half_of_puzzle).map_err(|e| e.with_file(input_path))?)
+}
+
+pub fn get_total_points(matches: &[Match], rules: &RuleSet) -> usize {
+    matches
+        .iter()
+        .map(|m| m.own_choice.get_hand_points(rules) + m.get_match_points(rules))
+        .sum()
+}
+
+/// The strategy guide decoded with both interpretations of the second column (see get_matches_from_input()), using the rules of the
+/// puzzle (see rules::CLASSIC_RULES)
+pub struct StrategyGuide {
+    pub rules: RuleSet,
+    pub matches_first_half: Vec<Match>,
+    pub matches_second_half: Vec<Match>
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = StrategyGuide;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<StrategyGuide, ParseError> {
+        let rules = RuleSet::classic();
+        Ok(StrategyGuide {
+            matches_first_half: parse_matches(input, &rules, false)?,
+            matches_second_half: parse_matches(input, &rules, true)?,
+            rules
+        })
+    }
+
+    fn part1(guide: &StrategyGuide) -> Result<usize> {
+        Ok(get_total_points(&guide.matches_first_half, &guide.rules))
+    }
+
+    fn part2(guide: &StrategyGuide) -> Result<usize> {
+        Ok(get_total_points(&guide.matches_second_half, &guide.rules))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_first_half() {
+        let guide = Day2::read_input("../inputs/day2_example.txt").unwrap();
+        assert_eq!(Day2::part1(&guide).unwrap(), 15);
+    }
+
+    #[test]
+    fn example_second_half() {
+        let guide = Day2::read_input("../inputs/day2_example.txt").unwrap();
+        assert_eq!(Day2::part2(&guide).unwrap(), 12);
+    }
+
+    #[test]
+    fn rpsls() {
+        let rules = RuleSet::rpsls();
+
+        // Spock vs. Lizard (loss), Rock vs. Paper (loss), Scissors vs. Paper (win)
+        let matches = parse_matches("D Z\nB V\nB X\n", &rules, false).unwrap();
+        assert_eq!(get_total_points(&matches, &rules), 5 + 1 + (3 + 6));
+
+        // Win against Lizard and loss against Spock (both with Scissors or Rock, Scissors has more points) and draw with Paper
+        let matches = parse_matches("D Z\nB Y\nE X\n", &rules, true).unwrap();
+        assert_eq!(get_total_points(&matches, &rules), (3 + 6) + (2 + 3) + 3);
+
+        let e = parse_matches("F X\n", &rules, false).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (1, 1, "Invalid hand symbol: F"));
+    }
+}
@@ -0,0 +1,225 @@
+//! Optimizer for the strategy guide (printed by the day2 binary with --optimize): The best own hands against the opponent column alone
+//! and the decoding of the guide's symbols that scores the most points.
+
+use crate::{invalid_symbol, split_input_line, HandChoice, Match, Outcome, RuleSet};
+use anyhow::{bail, Result};
+use common::ParseError;
+use std::cmp::Reverse;
+
+/// Maximum number of hands for searching all decodings (n! x n! decodings are scored, i.e. 14400 for five hands, but already about 25
+/// million for seven hands).
+pub const MAX_DECODING_HANDS: usize = 5;
+
+/// Desired outcomes the own symbols can stand for.
+const OUTCOMES: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+/// Returns the own hand that scores the most points against the opponent's hand (the first one in the table for equal points).
+pub fn best_response(opponent_choice: HandChoice, rules: &RuleSet) -> HandChoice {
+    rules
+        .choices()
+        .rev()
+        .map(|own_choice| Match { opponent_choice, own_choice })
+        .max_by_key(|m| m.own_choice.get_hand_points(rules) + m.get_match_points(rules))
+        .unwrap()  // A rule set has at least three hands
+        .own_choice
+}
+
+/// Returns the matches with the best own hand against each of the opponent's hands, which together score the maximum total points.
+pub fn best_responses(opponent_choices: &[HandChoice], rules: &RuleSet) -> Vec<Match> {
+    opponent_choices
+        .iter()
+        .map(|opponent_choice| Match { opponent_choice: *opponent_choice, own_choice: best_response(*opponent_choice, rules) })
+        .collect()
+}
+
+/// Parses the opponent column of a strategy guide. A second column is ignored, so both a full guide and a list of opponent symbols
+/// can be given.
+pub fn parse_opponent_column(input: &str, rules: &RuleSet) -> Result<Vec<HandChoice>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            let symbol = line.chars().next().ok_or_else(|| ParseError::in_line(line_idx, line, "Input line must not be empty!"))?;
+            if line.chars().nth(1).is_some_and(|c| c != ' ') {
+                return Err(ParseError::in_line(line_idx, line, "Input line must start with a single opponent symbol!"));
+            }
+            rules.decode_opponent_symbol(symbol).ok_or_else(|| invalid_symbol(line_idx, 0, line, symbol))
+        })
+        .collect()
+}
+
+/// Meanings of the own symbols of a strategy guide (in the order of the rule table).
+#[derive(Clone, Debug, PartialEq)]
+pub enum OwnMeanings {
+    Hands(Vec<HandChoice>),
+    /// Only possible for rule sets with three hands
+    Outcomes(Vec<Outcome>)
+}
+
+/// Assignment of meanings to the symbols of a strategy guide together with the total points of the guide decoded this way.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decoding {
+    /// Hands of the opponent symbols (in the order of the rule table)
+    pub opponent_hands: Vec<HandChoice>,
+    pub own_meanings: OwnMeanings,
+    pub total_points: usize
+}
+
+impl Decoding {
+    /// Describes the decoding like "A=Rock B=Paper C=Scissors, X=Loss Y=Draw Z=Win".
+    pub fn describe(&self, rules: &RuleSet) -> String {
+        let opponent: Vec<String> = rules
+            .hands()
+            .iter()
+            .zip(&self.opponent_hands)
+            .map(|(h, choice)| format!("{}={}", h.opponent_symbol, rules.hand(*choice).name))
+            .collect();
+        let own: Vec<String> = match &self.own_meanings {
+            OwnMeanings::Hands(choices) => rules
+                .hands()
+                .iter()
+                .zip(choices)
+                .map(|(h, choice)| format!("{}={}", h.own_symbol, rules.hand(*choice).name))
+                .collect(),
+            OwnMeanings::Outcomes(outcomes) => rules
+                .hands()
+                .iter()
+                .zip(outcomes)
+                .map(|(h, outcome)| format!("{}={:?}", h.own_symbol, outcome))
+                .collect()
+        };
+
+        format!("{}, {}", opponent.join(" "), own.join(" "))
+    }
+}
+
+/// Returns all orderings of the items.
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return vec!(vec!());
+    }
+
+    let mut res = vec!();
+    for (idx, item) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+        rest.remove(idx);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, item.clone());
+            res.push(permutation);
+        }
+    }
+    res
+}
+
+/// Scores the strategy guide with every mapping of the opponent symbols to hands, combined with every mapping of the own symbols to
+/// hands and (for rule sets with three hands) to desired outcomes, i.e. 3! x 3! mappings per interpretation for the puzzle's rules.
+/// Returns all decodings from the highest to the lowest total points (in the order of the search for equal points). Fails for rule sets
+/// with more than MAX_DECODING_HANDS hands.
+pub fn rank_decodings(input: &str, rules: &RuleSet) -> Result<Vec<Decoding>> {
+    let n = rules.hands().len();
+    if n > MAX_DECODING_HANDS {
+        bail!("Searching all decodings is only supported for up to {} hands (the rule set has {})!", MAX_DECODING_HANDS, n);
+    }
+
+    // Only the number of occurrences of each symbol pair matters for the total points
+    let mut counts = vec![vec![0; n]; n];
+    for (line_idx, line) in input.lines().enumerate() {
+        let (opponent_symbol, own_symbol) = split_input_line(line_idx, line)?;
+        let opponent_idx = rules
+            .decode_opponent_symbol(opponent_symbol)
+            .ok_or_else(|| invalid_symbol(line_idx, 0, line, opponent_symbol))?;
+        let own_idx = rules.decode_own_symbol(own_symbol).ok_or_else(|| invalid_symbol(line_idx, 2, line, own_symbol))?;
+        counts[opponent_idx.index()][own_idx.index()] += 1;
+    }
+
+    let hand_permutations = permutations(&rules.choices().collect::<Vec<_>>());
+    let mut own_meanings: Vec<OwnMeanings> = hand_permutations.iter().cloned().map(OwnMeanings::Hands).collect();
+    if n == OUTCOMES.len() {
+        own_meanings.extend(permutations(&OUTCOMES).into_iter().map(OwnMeanings::Outcomes));
+    }
+
+    let mut decodings = vec!();
+    for meanings in &own_meanings {
+        for opponent_hands in &hand_permutations {
+            let mut total_points = 0;
+            for (opponent_idx, row) in counts.iter().enumerate() {
+                for (own_idx, count) in row.iter().enumerate().filter(|(_, count)| **count > 0) {
+                    let opponent_choice = opponent_hands[opponent_idx];
+                    let own_choice = match meanings {
+                        OwnMeanings::Hands(choices) => choices[own_idx],
+                        OwnMeanings::Outcomes(outcomes) => rules.choose_hand(opponent_choice, outcomes[own_idx])
+                    };
+                    let m = Match { opponent_choice, own_choice };
+                    total_points += count * (m.own_choice.get_hand_points(rules) + m.get_match_points(rules));
+                }
+            }
+
+            decodings.push(Decoding { opponent_hands: opponent_hands.clone(), own_meanings: meanings.clone(), total_points });
+        }
+    }
+
+    decodings.sort_by_key(|d| Reverse(d.total_points));
+    Ok(decodings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_total_points;
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn best_responses_example() {
+        let rules = RuleSet::classic();
+        let opponent_choices = parse_opponent_column(EXAMPLE, &rules).unwrap();
+        assert_eq!(opponent_choices, parse_opponent_column("A\nB\nC\n", &rules).unwrap());
+
+        // Paper against Rock, Scissors against Paper and Rock against Scissors
+        let matches = best_responses(&opponent_choices, &rules);
+        let own_choices: Vec<usize> = matches.iter().map(|m| m.own_choice.index()).collect();
+        assert_eq!(own_choices, vec!(1, 2, 0));
+        assert_eq!(get_total_points(&matches, &rules), 8 + 9 + 7);
+
+        assert_eq!(parse_opponent_column("AY\n", &rules).unwrap_err().message, "Input line must start with a single opponent symbol!");
+        assert_eq!(parse_opponent_column("X\n", &rules).unwrap_err().message, "Invalid hand symbol: X");
+    }
+
+    #[test]
+    fn decodings_example() {
+        let rules = RuleSet::classic();
+        let decodings = rank_decodings(EXAMPLE, &rules).unwrap();
+        assert_eq!(decodings.len(), 2 * 6 * 6);
+
+        // Every line can be decoded as the best response, which beats both interpretations of the puzzle
+        assert_eq!(decodings[0].total_points, 24);
+        assert_eq!(decodings[0].describe(&rules), "A=Rock B=Scissors C=Paper, X=Rock Y=Paper Z=Scissors");
+
+        let identity: Vec<HandChoice> = rules.choices().collect();
+        let first_half = decodings.iter().find(|d| d.opponent_hands == identity && d.own_meanings == OwnMeanings::Hands(identity.clone()));
+        assert_eq!(first_half.unwrap().total_points, 15);
+        let second_half = decodings
+            .iter()
+            .find(|d| d.opponent_hands == identity && d.own_meanings == OwnMeanings::Outcomes(OUTCOMES.to_vec()))
+            .unwrap();
+        assert_eq!(second_half.total_points, 12);
+        assert_eq!(second_half.describe(&rules), "A=Rock B=Paper C=Scissors, X=Loss Y=Draw Z=Win");
+    }
+
+    #[test]
+    fn decodings_rpsls() {
+        // Without outcomes for five hands
+        let rules = RuleSet::rpsls();
+        let decodings = rank_decodings("A V\nE Z\n", &rules).unwrap();
+        assert_eq!(decodings.len(), 120 * 120);
+        assert!(decodings.iter().all(|d| matches!(d.own_meanings, OwnMeanings::Hands(_))));
+
+        // Spock against Rock and Lizard against Spock (the own symbols must stand for different hands)
+        assert_eq!(decodings[0].total_points, (5 + 6) + (4 + 6));
+
+        let table: String = (0..7).map(|x| format!("H{} 1 {} {} H{} H{} H{}\n", x, (b'A' + x) as char, (b'R' + x) as char, (x + 1) % 7,
+            (x + 2) % 7, (x + 4) % 7)).collect();
+        let err = rank_decodings("A R\n", &RuleSet::parse(&table).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "Searching all decodings is only supported for up to 5 hands (the rule set has 7)!");
+    }
+}
@@ -0,0 +1,272 @@
+//! Rule sets of cyclic hand games like Rock-Paper-Scissors with an odd number of hands, defined by a rule table.
+
+use common::ParseError;
+
+/// Rule table of the puzzle. Every line defines a hand: Its name, its points, its symbol in the first (opponent) and second (own) column
+/// of the strategy guide and the names of the hands it beats. Everything after a # is a comment.
+pub const CLASSIC_RULES: &str = "\
+# Name     Points  Opponent  Own  Beats
+Rock       1       A         X    Scissors
+Paper      2       B         Y    Rock
+Scissors   3       C         Z    Paper
+";
+
+/// Rock-Paper-Scissors-Lizard-Spock (the own hands use the symbols V to Z).
+pub const RPSLS_RULES: &str = "\
+# Name     Points  Opponent  Own  Beats
+Rock       1       A         V    Scissors Lizard
+Paper      2       B         W    Rock Spock
+Scissors   3       C         X    Paper Lizard
+Lizard     4       D         Y    Spock Paper
+Spock      5       E         Z    Scissors Rock
+";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win
+}
+
+impl Outcome {
+    /// Decodes the desired outcome from the second column of the strategy guide (second half of the puzzle).
+    pub fn decode_char(c: char) -> Option<Self> {
+        match c {
+            'X' => Some(Outcome::Loss),
+            'Y' => Some(Outcome::Draw),
+            'Z' => Some(Outcome::Win),
+            _ => None
+        }
+    }
+
+    pub fn get_points(&self) -> usize {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6
+        }
+    }
+}
+
+/// Index of a hand in the table of its rule set (only created by the rule set, so the index is always valid for it).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HandChoice(usize);
+
+impl HandChoice {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    pub fn get_hand_points(&self, rules: &RuleSet) -> usize {
+        rules.hand(*self).points
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hand {
+    pub name: String,
+    pub points: usize,
+    pub opponent_symbol: char,
+    pub own_symbol: char
+}
+
+/// Hands of a game where every hand beats exactly half of the other hands (so the number of hands is odd) and loses against the rest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleSet {
+    hands: Vec<Hand>,
+    /// beats[x][y] is true if hand x beats hand y
+    beats: Vec<Vec<bool>>
+}
+
+/// Parses a column that has to consist of a single character.
+fn parse_symbol(line_idx: usize, line: &str, s: &str) -> Result<char, ParseError> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ParseError::in_line(line_idx, line, format!("Symbol must be a single character: {}", s)))
+    }
+}
+
+impl RuleSet {
+    /// Parses a rule table (see CLASSIC_RULES for the format) and checks that it defines a valid game.
+    pub fn parse(table: &str) -> Result<RuleSet, ParseError> {
+        let mut hands: Vec<Hand> = vec!();
+        let mut beaten_names = vec!();
+
+        for (line_idx, line) in table.lines().enumerate() {
+            let columns: Vec<&str> = line.split('#').next().unwrap().split_whitespace().collect();
+            if columns.is_empty() {
+                continue;
+            } else if columns.len() < 4 {
+                return Err(ParseError::in_line(line_idx, line, "Expected the name, points, opponent symbol, own symbol and beaten hands!"));
+            }
+
+            let hand = Hand {
+                name: columns[0].to_string(),
+                points: columns[1].parse().map_err(|_| ParseError::in_line(line_idx, line, format!("Invalid points: {}", columns[1])))?,
+                opponent_symbol: parse_symbol(line_idx, line, columns[2])?,
+                own_symbol: parse_symbol(line_idx, line, columns[3])?
+            };
+
+            if let Some(other) = hands.iter().find(|h| h.name == hand.name || h.opponent_symbol == hand.opponent_symbol ||
+                h.own_symbol == hand.own_symbol) {
+                let message = format!("Name or symbols of {} are already used by {}!", hand.name, other.name);
+                return Err(ParseError::in_line(line_idx, line, message));
+            }
+
+            hands.push(hand);
+            beaten_names.push((line_idx, line, columns[4..].to_vec()));
+        }
+
+        let n = hands.len();
+        if n < 3 || n.is_multiple_of(2) {
+            let message = format!("Rule table must define an odd number of at least three hands (found {})!", n);
+            return Err(ParseError::at_end(table, message));
+        }
+
+        let mut beats = vec![vec![false; n]; n];
+        for (x, (line_idx, line, names)) in beaten_names.into_iter().enumerate() {
+            for name in names {
+                let y = hands.iter().position(|h| h.name == name).ok_or_else(|| ParseError::in_line(line_idx, line,
+                    format!("Unknown hand: {}", name)))?;
+                if x == y {
+                    return Err(ParseError::in_line(line_idx, line, format!("{} cannot beat itself!", name)));
+                }
+                beats[x][y] = true;
+            }
+
+            let beaten_count = beats[x].iter().filter(|b| **b).count();
+            if beaten_count != n / 2 {
+                let message = format!("{} beats {} hand(s), but every hand has to beat exactly {} of the {} hands!", hands[x].name,
+                    beaten_count, n / 2, n);
+                return Err(ParseError::in_line(line_idx, line, message));
+            }
+        }
+
+        for x in 0..n {
+            for y in (x + 1)..n {
+                if beats[x][y] == beats[y][x] {
+                    return Err(ParseError::at_end(table, format!("Exactly one of {} and {} has to beat the other!", hands[x].name,
+                        hands[y].name)));
+                }
+            }
+        }
+
+        Ok(RuleSet { hands, beats })
+    }
+
+    /// Rules of the puzzle (see CLASSIC_RULES).
+    pub fn classic() -> RuleSet {
+        RuleSet::parse(CLASSIC_RULES).expect("Built-in rule table is invalid!")
+    }
+
+    /// Rules of Rock-Paper-Scissors-Lizard-Spock (see RPSLS_RULES).
+    pub fn rpsls() -> RuleSet {
+        RuleSet::parse(RPSLS_RULES).expect("Built-in rule table is invalid!")
+    }
+
+    pub fn hands(&self) -> &[Hand] {
+        &self.hands
+    }
+
+    /// Returns the choices of all hands in the order of the table.
+    pub fn choices(&self) -> impl DoubleEndedIterator<Item = HandChoice> {
+        (0..self.hands.len()).map(HandChoice)
+    }
+
+    /// Returns the choice of the hand with the given index in the table (None if there is no such hand).
+    pub fn choice(&self, index: usize) -> Option<HandChoice> {
+        (index < self.hands.len()).then_some(HandChoice(index))
+    }
+
+    pub fn hand(&self, choice: HandChoice) -> &Hand {
+        &self.hands[choice.0]
+    }
+
+    pub fn decode_opponent_symbol(&self, c: char) -> Option<HandChoice> {
+        self.hands.iter().position(|h| h.opponent_symbol == c).map(HandChoice)
+    }
+
+    pub fn decode_own_symbol(&self, c: char) -> Option<HandChoice> {
+        self.hands.iter().position(|h| h.own_symbol == c).map(HandChoice)
+    }
+
+    /// Returns the outcome of a match for the own hand.
+    pub fn get_outcome(&self, own: HandChoice, opponent: HandChoice) -> Outcome {
+        if own == opponent {
+            Outcome::Draw
+        } else if self.beats[own.0][opponent.0] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// Returns the own hand that leads to the desired outcome against the opponent's hand. With more than three hands, several hands
+    /// lead to a loss or a win: The one with the most points is chosen then (the first one in the table for equal points).
+    pub fn choose_hand(&self, opponent: HandChoice, outcome: Outcome) -> HandChoice {
+        self.choices()
+            .rev()
+            .filter(|own| self.get_outcome(*own, opponent) == outcome)
+            .max_by_key(|own| self.hand(*own).points)
+            .unwrap()  // Every hand beats and loses against at least one other hand
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_rules() {
+        let rules = RuleSet::classic();
+        assert_eq!(rules.hands().iter().map(|h| h.name.as_str()).collect::<Vec<_>>(), vec!("Rock", "Paper", "Scissors"));
+
+        // Same as the modular rule for three hands: x beats y exactly when (x - y) % 3 == 1
+        for x in 0..3 {
+            for y in 0..3 {
+                let expected = match (x as isize - y as isize).rem_euclid(3) {
+                    0 => Outcome::Draw,
+                    1 => Outcome::Win,
+                    _ => Outcome::Loss
+                };
+                assert_eq!(rules.get_outcome(HandChoice(x), HandChoice(y)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn rpsls_rules() {
+        let rules = RuleSet::rpsls();
+        let hand = |name: &str| HandChoice(rules.hands().iter().position(|h| h.name == name).unwrap());
+
+        assert_eq!(rules.get_outcome(hand("Spock"), hand("Rock")), Outcome::Win);
+        assert_eq!(rules.get_outcome(hand("Lizard"), hand("Spock")), Outcome::Win);
+        assert_eq!(rules.get_outcome(hand("Paper"), hand("Lizard")), Outcome::Loss);
+        assert_eq!(rules.decode_own_symbol('Y'), Some(hand("Lizard")));
+        assert_eq!(rules.choice(3), Some(hand("Lizard")));
+        assert_eq!((rules.choice(5), rules.choices().count()), (None, 5));
+
+        // Paper and Spock beat Rock, Spock has more points
+        assert_eq!(rules.choose_hand(hand("Rock"), Outcome::Win), hand("Spock"));
+        assert_eq!(rules.choose_hand(hand("Rock"), Outcome::Loss), hand("Lizard"));
+        assert_eq!(rules.choose_hand(hand("Rock"), Outcome::Draw), hand("Rock"));
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let error = |table: &str| RuleSet::parse(table).unwrap_err().message;
+
+        assert_eq!(error("Rock 1 A X Paper\nPaper 2 B Y\n"), "Rule table must define an odd number of at least three hands (found 2)!");
+        assert_eq!(error("# Comment\n"), "Rule table must define an odd number of at least three hands (found 0)!");
+        assert_eq!(error("Rock 1 A X Scissors\nPaper 2 B Y Rock\nScissors 3 C Z Spock\n"), "Unknown hand: Spock");
+        assert_eq!(error("Rock 1 A X Rock\nPaper 2 B Y Rock\nScissors 3 C Z Paper\n"), "Rock cannot beat itself!");
+        assert_eq!(error("Rock 1 A X Scissors\nPaper 2 B Y Rock\nScissors 3 C Z Rock\n"),
+            "Exactly one of Rock and Scissors has to beat the other!");
+        assert_eq!(error("Rock 1 A X Scissors Paper\nPaper 2 B Y\nScissors 3 C Z Paper\n"),
+            "Rock beats 2 hand(s), but every hand has to beat exactly 1 of the 3 hands!");
+        assert_eq!(error("Rock 1 A X Scissors\nPaper 2 A Y Rock\n"), "Name or symbols of Paper are already used by Rock!");
+        assert_eq!(error("Rock 1 AB X Scissors\n"), "Symbol must be a single character: AB");
+        assert_eq!(error("Rock one A X Scissors\n"), "Invalid points: one");
+    }
+}
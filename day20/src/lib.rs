@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::trace;
+
+/// Returns the name at the position given by the index (wrapping the list as needed), where the index 0 contains to the position of the
+/// number zero in the list.
+pub fn get_number_by_wrapping_index_from_zero(numbers: &[i128], index: usize) -> i128 {
+    let zero_position = numbers.iter().position(|x| *x == 0).expect("No zero in list?!");
+    let pos = (zero_position + index) % numbers.len();
+    numbers[pos]
+}
+
+/// Sums up the numbers at the (wrapped) positions 1000, 2000 and 3000 after the number zero.
+pub fn get_grove_coordinates_sum(numbers: &[i128]) -> i128 {
+    get_number_by_wrapping_index_from_zero(numbers, 1000) +
+        get_number_by_wrapping_index_from_zero(numbers, 2000) +
+        get_number_by_wrapping_index_from_zero(numbers, 3000)
+}
+
+pub fn mix_sequence(numbers: &mut Vec<i128>, num_rounds: usize) {
+    let len = numbers.len();
+
+    // A single number cannot move anywhere (and the new index below would be computed modulo zero)
+    if len < 2 {
+        return;
+    }
+
+    // Build list of increasing numbers (which is also shuffled) to determine which number to move next
+    let mut order_list: Vec<usize> = (0..len).collect();
+
+    for _ in 0..num_rounds {
+        // Shuffle each number once
+        for cur_order in 0..len {
+            // Determine where this number is now
+            let old_index = order_list.iter().position(|x| *x == cur_order).expect("Order number not found?!");
+
+            // Get number to shuffle and determine new index (wrapped)
+            let cur_number = numbers[old_index];
+            let new_index = (old_index as i128 + cur_number).rem_euclid(len as i128 - 1) as usize;
+            trace!(cur_number, old_index, new_index, "Moving number");
+
+            // Move entries both in order list and in actual number list (very inefficient due to the use of Vec)
+            order_list.remove(old_index);
+            numbers.remove(old_index);
+            order_list.insert(new_index, cur_order);
+            numbers.insert(new_index, cur_number);
+        }
+
+        trace!(?numbers, "Mixed numbers");
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i128>, ParseError> {
+    let res: Vec<i128> = input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| l.parse().map_err(|_| ParseError::in_line(line_idx, l, "Could not parse number!")))
+        .collect::<Result<_, _>>()?;
+
+    // The grove coordinates are determined relative to the number zero
+    if !res.contains(&0) {
+        return Err(ParseError::at_end(input, "The list does not contain the number zero!"));
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<i128>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i128>;
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Vec<i128>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Vec<i128>) -> Result<i128> {
+        let mut numbers = numbers.clone();
+        mix_sequence(&mut numbers, 1);
+        Ok(get_grove_coordinates_sum(&numbers))
+    }
+
+    fn part2(numbers: &Vec<i128>) -> Result<i128> {
+        let mut numbers = numbers.iter().map(|x| x * 811589153).collect();
+        mix_sequence(&mut numbers, 10);
+        Ok(get_grove_coordinates_sum(&numbers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example_part1() {
+        let mut numbers = read_input_file("../inputs/day20_example.txt").unwrap();
+        mix_sequence(&mut numbers, 1);
+        assert_eq!(get_number_by_wrapping_index_from_zero(&numbers, 1000), 4);
+        assert_eq!(get_number_by_wrapping_index_from_zero(&numbers, 2000), -3);
+        assert_eq!(get_number_by_wrapping_index_from_zero(&numbers, 3000), 2);
+        assert_eq!(get_grove_coordinates_sum(&numbers), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        let numbers = read_input_file("../inputs/day20_example.txt").unwrap();
+        let mut numbers = numbers.iter().map(|x| x * 811589153).collect();
+        mix_sequence(&mut numbers, 10);
+        assert_eq!(get_number_by_wrapping_index_from_zero(&numbers, 1000), 811589153);
+        assert_eq!(get_number_by_wrapping_index_from_zero(&numbers, 2000), 2434767459);
+        assert_eq!(get_number_by_wrapping_index_from_zero(&numbers, 3000), -1623178306);
+        assert_eq!(get_grove_coordinates_sum(&numbers), 1623178306);
+    }
+
+    /// Puzzle input with one number per line (always including a zero) together with the generated numbers.
+    fn numbers_input() -> impl Strategy<Value = (String, Vec<i128>)> {
+        (prop::collection::vec(-10_000..10_000i128, 0..40), any::<prop::sample::Index>()).prop_map(|(mut numbers, zero_index)| {
+            numbers.insert(zero_index.index(numbers.len() + 1), 0);
+            let input = numbers.iter().map(|n| format!("{}\n", n)).collect();
+            (input, numbers)
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn mixing_preserves_numbers((input, numbers) in numbers_input(), key in prop_oneof!(Just(1), Just(811589153)), rounds in 1..4usize) {
+            let mut mixed: Vec<i128> = parse_input(&input).unwrap().iter().map(|x| x * key).collect();
+            mix_sequence(&mut mixed, rounds);
+
+            let mut expected: Vec<i128> = numbers.iter().map(|x| x * key).collect();
+            expected.sort_unstable();
+            mixed.sort_unstable();
+            prop_assert_eq!(mixed, expected);
+        }
+    }
+}
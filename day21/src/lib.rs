@@ -0,0 +1,195 @@
+use anyhow::{bail, Context, Result};
+use common::{ParseError, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::Path;
+
+#[derive(Clone, Debug)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div
+}
+
+#[derive(Clone, Debug)]
+pub enum Monkey {
+    Constant { number: i64 },
+    Calculation { operator: Operator, operand1: String, operand2: String } 
+}
+
+pub type MonkeyMap = HashMap<String, Monkey>;
+
+/// Looks up the monkey with the given name.
+fn get_monkey<'a>(monkeys: &'a MonkeyMap, monkey_name: &str) -> Result<&'a Monkey> {
+    monkeys.get(monkey_name).with_context(|| format!("Monkey {} not found!", monkey_name))
+}
+
+/// Fails if the calculation overflows or divides by zero.
+fn calculate(operator: &Operator, a: i64, b: i64) -> Result<i64> {
+    match operator {
+        Operator::Add => a.checked_add(b),
+        Operator::Sub => a.checked_sub(b),
+        Operator::Mul => a.checked_mul(b),
+        Operator::Div => a.checked_div(b)
+    }
+    .with_context(|| format!("Calculation {:?} of {} and {} overflows or divides by zero!", operator, a, b))
+}
+
+/// Recursively calculates the value a monkey will shout (if ignore_humn is false for the first part of the puzzle) or the value but only
+/// if the calculation tree does not contain the "humn" monkey (if ignore_humn is true for the second part of the puzzle).
+pub fn eval_monkey(monkeys: &MonkeyMap, monkey_name: &str, ignore_humn: bool) -> Result<Option<i64>> {
+    if ignore_humn && monkey_name == "humn" {
+        return Ok(None);
+    }
+
+    match get_monkey(monkeys, monkey_name)? {
+        Monkey::Constant { number } => Ok(Some(*number)),
+        Monkey::Calculation { operator , operand1, operand2 } => {
+            if let (Some(a), Some(b)) = (eval_monkey(monkeys, operand1, ignore_humn)?, eval_monkey(monkeys, operand2, ignore_humn)?) {
+                Ok(Some(calculate(operator, a, b)?))
+            } else {
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Determines the number the human needs to yell to make the equation for the root monkey valid (second part of the puzzle). Reverses the
+/// calculation and determines the number that the human must yell to get the desired result. This function assumes that the subtree
+/// traversed contains exactly one "humn" node, otherwise it will fail.
+pub fn eval_human_number(monkeys: &MonkeyMap, monkey_name: &str, desired_result: i64) -> Result<i64> {
+    if monkey_name == "humn" {
+        return Ok(desired_result);
+    }
+
+    match get_monkey(monkeys, monkey_name)? {
+        Monkey::Calculation { operator, operand1, operand2 } => {
+            let maybe_value1 = eval_monkey(monkeys, operand1, true)?;
+            let maybe_value2 = eval_monkey(monkeys, operand2, true)?;
+
+            // Exactly one of the two subtrees must yield a number and the other one must contain the human
+            match (maybe_value1, maybe_value2) {
+                (Some(x), None) => eval_human_number(monkeys, operand2, match operator {
+                    Operator::Add => calculate(&Operator::Sub, desired_result, x)?,
+                    Operator::Sub => calculate(&Operator::Sub, x, desired_result)?,
+                    Operator::Mul => calculate(&Operator::Div, desired_result, x)?,
+                    Operator::Div => calculate(&Operator::Div, x, desired_result)?
+                }),
+                (None, Some(y)) => eval_human_number(monkeys, operand1, match operator {
+                    Operator::Add => calculate(&Operator::Sub, desired_result, y)?,
+                    Operator::Sub => calculate(&Operator::Add, desired_result, y)?,
+                    Operator::Mul => calculate(&Operator::Div, desired_result, y)?,
+                    Operator::Div => calculate(&Operator::Mul, desired_result, y)?
+                }),
+                _ => bail!("No human found or human in both subtrees of monkey {}!", monkey_name)
+            }
+        },
+        _ => bail!("Monkey {} on the path to the human must not be a constant!", monkey_name)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<MonkeyMap, ParseError> {
+    let re = Regex::new(r"^(?P<name>[a-z]{4}): ((?P<number>\d+)|(?P<operand1>[a-z]{4}) (?P<operator>[\+\-*/]) (?P<operand2>[a-z]{4}))$").unwrap();
+
+    let res: MonkeyMap = input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| {
+            let caps = re.captures(l).ok_or_else(|| ParseError::in_line(line_idx, l, "Could not parse monkey description!"))?;
+            let name = caps.name("name").unwrap().as_str().into();
+
+            if let Some(cap_number) = caps.name("number") {
+                let number = cap_number.as_str().parse().map_err(|_| ParseError::new(line_idx, cap_number.start(), l, "Could not parse number!"))?;
+                Ok((name, Monkey::Constant { number }))
+            } else {
+                Ok((name, Monkey::Calculation {
+                    operator: match caps.name("operator").unwrap().as_str() {
+                        "+" => Operator::Add,
+                        "-" => Operator::Sub,
+                        "*" => Operator::Mul,
+                        _ => Operator::Div  // Regex only matches the four operators
+                    },
+                    operand1: caps.name("operand1").unwrap().as_str().into(),
+                    operand2: caps.name("operand2").unwrap().as_str().into(),
+                }))
+            }
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    // Make sure that all operands refer to known monkeys (the solver looks them up without checking)
+    for (line_idx, l) in input.lines().enumerate() {
+        let caps = re.captures(l).unwrap();
+        for operand in ["operand1", "operand2"].iter().filter_map(|name| caps.name(name)) {
+            if !res.contains_key(operand.as_str()) {
+                return Err(ParseError::new(line_idx, operand.start(), l, format!("Unknown monkey {}!", operand.as_str())));
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<MonkeyMap> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = MonkeyMap;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<MonkeyMap, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &MonkeyMap) -> Result<i64> {
+        eval_monkey(monkeys, "root", false)?.context("Could not evaluate root monkey!")
+    }
+
+    fn part2(monkeys: &MonkeyMap) -> Result<i64> {
+        // Override operation in root monkey to subtract (this way if the difference is zero, both operands are equal)
+        let mut monkeys = monkeys.clone();
+        if let Some(Monkey::Calculation { operator: _, operand1, operand2 }) = monkeys.get("root") {
+            monkeys.insert("root".into(), Monkey::Calculation { operator: Operator::Sub, operand1: operand1.clone(), operand2: operand2.clone() });
+        }
+
+        eval_human_number(&monkeys, "root", 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let monkeys = Day21::read_input("../inputs/day21_example.txt").unwrap();
+        assert_eq!(Day21::part1(&monkeys).unwrap(), 152);
+    }
+
+    #[test]
+    fn example_part2() {
+        let monkeys = Day21::read_input("../inputs/day21_example.txt").unwrap();
+        assert_eq!(Day21::part2(&monkeys).unwrap(), 301);
+    }
+
+    #[test]
+    fn invalid_calculations() {
+        let monkeys = parse_input("").unwrap();
+        assert_eq!(Day21::part1(&monkeys).unwrap_err().to_string(), "Monkey root not found!");
+        assert_eq!(Day21::part2(&monkeys).unwrap_err().to_string(), "Monkey root not found!");
+
+        let monkeys = parse_input("root: humn / zero\nhumn: 1\nzero: 0\n").unwrap();
+        assert_eq!(Day21::part1(&monkeys).unwrap_err().to_string(), "Calculation Div of 1 and 0 overflows or divides by zero!");
+
+        let monkeys = parse_input("root: huge * huge\nhuge: 9223372036854775807\n").unwrap();
+        assert_eq!(Day21::part1(&monkeys).unwrap_err().to_string(),
+            "Calculation Mul of 9223372036854775807 and 9223372036854775807 overflows or divides by zero!");
+        assert_eq!(Day21::part2(&monkeys).unwrap_err().to_string(), "No human found or human in both subtrees of monkey root!");
+    }
+}
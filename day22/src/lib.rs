@@ -0,0 +1,466 @@
+use anyhow::{ensure, Context, Result};
+use common::{ParseError, Solution};
+use grid::{Direction, Grid};
+use std::fs::read_to_string;
+use std::ops::Range;
+use std::path::Path;
+use tracing::trace;
+
+pub mod visualization;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Field {
+    Empty,
+    Blocked,
+    OffMap
+}
+
+pub type Board = Grid<Field>;
+
+pub enum Move {
+    Forward { steps: usize },
+    Turn { turn_right: bool }
+}
+
+#[derive(Clone, Debug)]
+pub struct Position {
+    row: usize,
+    column: usize,
+    dir: Direction
+}
+
+/// The start position is the leftmost free field in the top row, facing right.
+fn get_start_position(board: &Board) -> Position {
+    Position {
+        row: 0,
+        column: board.row(0).position(|x| *x == Field::Empty).expect("No free field found in top row!"),
+        dir: Direction::Right
+    }
+}
+
+pub fn get_final_position(board: &Board, moves: &[Move], cube_overflow: bool) -> Position {
+    let mut cur_pos = get_start_position(board);
+
+    // Apply all moves
+    for m in moves {
+        match m {
+            Move::Forward { steps } => {
+                for _ in 0..*steps {
+                    if let Some(new_pos) = move_forward_one_step(board, &cur_pos, cube_overflow) {
+                        cur_pos = new_pos;
+                        trace!(x = cur_pos.column, y = cur_pos.row, dir = ?cur_pos.dir, "Moved");
+                    } else {
+                        break;
+                    }
+                }
+            },
+            Move::Turn { turn_right } => {
+                cur_pos.dir = if *turn_right { cur_pos.dir.turn_right() } else { cur_pos.dir.turn_left() };
+            }
+        }
+    }
+
+    cur_pos
+}
+
+fn get_field(board: &Board, row: usize, column: usize) -> &Field {
+    &board[(column as i32, row as i32)]
+}
+
+fn get_first_valid_in_range_x(board: &Board, x: Range<usize>, y: usize) -> usize {
+    x.clone().find(|i| *get_field(board, y, *i) != Field::OffMap).expect("No overflow field found?!")
+}
+
+fn get_first_valid_in_range_y(board: &Board, x: usize, y: Range<usize>) -> usize {
+    y.clone().find(|i| *get_field(board, *i, x) != Field::OffMap).expect("No overflow field found?!")
+}
+
+fn get_last_valid_in_range_x(board: &Board, x: Range<usize>, y: usize) -> usize {
+    x.rev().find(|i| *get_field(board, y, *i) != Field::OffMap).expect("No overflow field found?!")
+}
+
+fn get_last_valid_in_range_y(board: &Board, x: usize, y: Range<usize>) -> usize {
+    y.rev().find(|i| *get_field(board, *i, x) != Field::OffMap).expect("No overflow field found?!")
+}
+
+/// Determine next field to potentially move to, potentially overflowing (ugly branching structure, to be implemented more elegantly)
+fn get_next_field(board: &Board, pos: &Position, cube_overflow: bool) -> Position {
+    match pos.dir {
+        Direction::Right => {
+            // Overflow at the right border over the board or if we would be off map
+            if pos.column != board.width() - 1 && *get_field(board, pos.row, pos.column + 1) != Field::OffMap {
+                return Position { row: pos.row, column: pos.column + 1, dir: pos.dir };
+            }
+        },
+        Direction::Down => {
+            // Overflow at the bottom border over the board or if we would be off map
+            if pos.row != board.height() - 1 && *get_field(board, pos.row + 1, pos.column) != Field::OffMap {
+                return Position { row: pos.row + 1, column: pos.column, dir: pos.dir };
+            }
+        },
+        Direction::Left => {
+            // Overflow at the left border over the board or if we would be off map
+            if pos.column != 0 && *get_field(board, pos.row, pos.column - 1) != Field::OffMap {
+                return Position { row: pos.row, column: pos.column - 1, dir: pos.dir };
+            }
+        },
+        Direction::Up => {
+            // Overflow at the top border over the board or if we would be off map
+            if pos.row != 0 && *get_field(board, pos.row - 1, pos.column) != Field::OffMap {
+                return Position { row: pos.row - 1, column: pos.column, dir: pos.dir };
+            }
+        }
+    }
+
+    // Handle overflow
+    if cube_overflow {
+        get_overflow_field_cube(board, pos)
+    } else {
+        get_overflow_field(board, pos)
+    }
+}
+
+/// Determines the "overflow field", i.e. the first field that is not off map (again, an ugly branching structure).
+fn get_overflow_field(board: &Board, pos: &Position) -> Position {
+    match pos.dir {
+        Direction::Right => {
+            Position {
+                row: pos.row,
+                column: board
+                    .row(pos.row)
+                    .position(|f| *f != Field::OffMap)
+                    .expect("No overflow field found?!"),
+                dir: pos.dir
+            }
+        },
+        Direction::Down => {
+            Position {
+                row: board
+                    .column(pos.column)
+                    .position(|f| *f != Field::OffMap)
+                    .expect("No overflow field found?!"),
+                column: pos.column,
+                dir: pos.dir
+            }
+        },
+        Direction::Left => {
+            Position {
+                row: pos.row,
+                column: board.width() - 1 - board
+                    .row(pos.row)
+                    .rev()
+                    .position(|f| *f != Field::OffMap)
+                    .expect("No overflow field found?!"),
+                dir: pos.dir
+            }
+        },
+        Direction::Up => {
+            Position {
+                row: board.height() - 1 - board
+                    .column(pos.column)
+                    .rev()
+                    .position(|f| *f != Field::OffMap)
+                    .expect("No overflow field found?!"),
+                column: pos.column,
+                dir: pos.dir
+            }
+        }
+    }
+}
+
+/// Cube faces of the hardcoded layout (see get_overflow_field_cube()) as (column, row) in units of the side length.
+const CUBE_FACES: [(usize, usize); 6] = [(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)];
+
+/// Checks that the board fits the cube layout hardcoded for the second part of the puzzle (the layout of the real puzzle inputs, the
+/// example has a different one), i.e. exactly the fields of the six faces are on the map.
+pub fn check_cube_layout(board: &Board) -> Result<()> {
+    let cube_sl = board.height() / 4;
+    let error = "Board does not fit the hardcoded cube layout (only the layout of the real puzzle inputs is supported)!";
+    ensure!(cube_sl > 0 && board.height() == cube_sl * 4 && board.width() == cube_sl * 3, error);
+
+    for row in 0..board.height() {
+        for column in 0..board.width() {
+            let on_face = CUBE_FACES.contains(&(column / cube_sl, row / cube_sl));
+            ensure!(on_face == (*get_field(board, row, column) != Field::OffMap), error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Determines the "overflow field" for the second part of the puzzle (the board must have been checked with check_cube_layout()).
+fn get_overflow_field_cube(board: &Board, pos: &Position) -> Position {
+    // Hardcoded cube layout
+    //  12
+    //  3
+    // 45
+    // 6
+
+    // Calculate side length of the cube to determine how we are overflowing
+    let cube_sl = board.height() / 4;
+    if board.width() != cube_sl * 3 { panic!("Board does not fit hardcoded cube structure!") }
+
+    let cube_face = if pos.row < cube_sl {
+        if pos.column < cube_sl { panic!("Invalid position, check hardcoded cube faces!"); }
+        else if pos.column < 2 * cube_sl { 1 }
+        else { 2 }
+    } else if pos.row < 2 * cube_sl {
+        if pos.column < cube_sl { panic!("Invalid position, check hardcoded cube faces!"); }
+        else if pos.column < 2 * cube_sl { 3 }
+        else { panic!("Invalid position, check hardcoded cube faces!"); }
+    } else if pos.row < 3 * cube_sl {
+        if pos.column < cube_sl { 4 }
+        else if pos.column < 2 * cube_sl { 5 }
+        else { panic!("Invalid position, check hardcoded cube faces!"); }
+    } else {
+        if pos.column < cube_sl { 6 }
+        else { panic!("Invalid position, check hardcoded cube faces!"); }
+    };
+
+    // Hardcode all possible transitions
+    match (cube_face, &pos.dir) {
+        (1, Direction::Up) => {
+            Position {
+                row: pos.column + 2 * cube_sl,
+                column: get_first_valid_in_range_x(board, 0..(cube_sl - 1), pos.column + 2 * cube_sl),
+                dir: Direction::Right
+            }
+        },
+        (1, Direction::Left) => {
+            Position {
+                row: 3 * cube_sl - 1 - pos.row,
+                column: get_first_valid_in_range_x(board, 0..(cube_sl - 1), 3 * cube_sl - 1 - pos.row),
+                dir: Direction::Right
+            }
+        },
+        (2, Direction::Up) => {
+            Position {
+                row: get_last_valid_in_range_y(board, pos.column - 2 * cube_sl, (3 * cube_sl)..(4 * cube_sl - 1)),
+                column: pos.column - 2 * cube_sl,
+                dir: Direction::Up
+            }
+        },
+        (2, Direction::Right) => {
+            Position {
+                row: 3 * cube_sl - 1 - pos.row,
+                column: get_last_valid_in_range_x(board, cube_sl..(2 * cube_sl - 1), 3 * cube_sl - 1 - pos.row),
+                dir: Direction::Left
+            }
+        },
+        (2, Direction::Down) => {
+            Position {
+                row: pos.column - cube_sl,
+                column: get_last_valid_in_range_x(board, cube_sl..(2 * cube_sl - 1), pos.column - cube_sl),
+                dir: Direction::Left
+            }
+        },
+        (3, Direction::Right) => {
+            Position {
+                row: get_last_valid_in_range_y(board, pos.row + cube_sl, 0..(cube_sl - 1)),
+                column: pos.row + cube_sl,
+                dir: Direction::Up
+            }
+        },
+        (3, Direction::Left) => {
+            Position {
+                row: get_first_valid_in_range_y(board, pos.row - cube_sl, (2 * cube_sl)..(3 * cube_sl - 1)),
+                column: pos.row - cube_sl,
+                dir: Direction::Down
+            }
+        },
+        (4, Direction::Up) => {
+            Position {
+                row: pos.column + cube_sl,
+                column: get_first_valid_in_range_x(board, cube_sl..(2 * cube_sl - 1), pos.column + cube_sl),
+                dir: Direction::Right
+            }
+        },
+        (4, Direction::Left) => {
+            Position {
+                row: 3 * cube_sl - 1 - pos.row,
+                column: get_first_valid_in_range_x(board, cube_sl..(2 * cube_sl - 1), 3 * cube_sl - 1 - pos.row),
+                dir: Direction::Right
+            }
+        },
+        (5, Direction::Right) => {
+            Position {
+                row: 3 * cube_sl - 1 - pos.row,
+                column: get_last_valid_in_range_x(board, (2 * cube_sl)..(3 * cube_sl - 1), 3 * cube_sl - 1 - pos.row),
+                dir: Direction::Left
+            }
+        },
+        (5, Direction::Down) => {
+            Position {
+                row: pos.row + cube_sl,
+                column: get_last_valid_in_range_x(board, 0..(cube_sl - 1), pos.row + cube_sl),
+                dir: Direction::Left
+            }
+        },
+        (6, Direction::Right) => {
+            Position {
+                row: get_last_valid_in_range_y(board, pos.row - 2 * cube_sl, (2 * cube_sl)..(3 * cube_sl - 1)),
+                column: pos.row - 2 * cube_sl,
+                dir: Direction::Up
+            }
+        },
+        (6, Direction::Down) => {
+            Position {
+                row: get_first_valid_in_range_y(board, pos.column + cube_sl, 0..(cube_sl - 1)),
+                column: pos.column + cube_sl,
+                dir: Direction::Down
+            }
+        },
+        (6, Direction::Left) => {
+            Position {
+                row: get_first_valid_in_range_y(board, pos.row - 2 * cube_sl, 0..(cube_sl - 1)),
+                column: pos.row - 2 * cube_sl,
+                dir: Direction::Down
+            }
+        },
+        (_, _) => panic!("Transition from cube face {} with direction {:?} not implemented!", cube_face, pos.dir)
+    }
+}
+
+pub fn get_password(pos: &Position) -> usize {
+    1000 * (pos.row + 1) + 4 * (pos.column + 1) + match pos.dir {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3
+    }
+}
+
+fn move_forward_one_step(board: &Board, pos: &Position, cube_overflow: bool) -> Option<Position> {
+    let new_pos = get_next_field(board, pos, cube_overflow);
+
+    // Check if new position is empty
+    if *get_field(board, new_pos.row, new_pos.column) == Field::Empty {
+        Some(new_pos)
+    } else {
+        None
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Board, Vec<Move>), ParseError> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    if lines.len() < 3 {
+        return Err(ParseError::at_end(input, "Too few lines (expected the board, an empty line and the moves)!"));
+    }
+
+    // Split off moves input from the board input
+    let move_line_idx = lines.len() - 1;
+    let move_line = lines.pop().unwrap();
+    let empty_line = lines.pop().unwrap();
+    if !empty_line.is_empty() {
+        return Err(ParseError::in_line(move_line_idx - 1, empty_line, "Second last line was not empty!"));
+    }
+
+    // Parse board (spaces are right-trimmed in the input file, so shorter lines are filled up with off-map fields)
+    let board = Grid::parse_padded(&lines.join("\n"), ' ', |_, c| match c {
+        '.' => Ok(Field::Empty),
+        '#' => Ok(Field::Blocked),
+        ' ' => Ok(Field::OffMap),
+        _ => Err(format!("Unknown character on board: {}", c))
+    })?;
+
+    if !board.row(0).any(|f| *f == Field::Empty) {
+        return Err(ParseError::in_line(0, lines[0], "First row of the board has no empty field to start on!"));
+    }
+
+    // Parse moves
+    let mut moves = vec!();
+    let mut cur_move_count_start = None;
+
+    let parse_move_count = |start: usize, end: usize| {
+        move_line[start..end]
+            .parse()
+            .map(|steps| Move::Forward { steps })
+            .map_err(|_| ParseError::new(move_line_idx, start, move_line, "Could not parse number!"))
+    };
+
+    for (col_idx, c) in move_line.char_indices() {
+        if c.is_ascii_digit() {
+            cur_move_count_start.get_or_insert(col_idx);
+        } else {
+            if let Some(start) = cur_move_count_start.take() {
+                moves.push(parse_move_count(start, col_idx)?);
+            }
+
+            match c {
+                'L' => moves.push(Move::Turn { turn_right: false }),
+                'R' => moves.push(Move::Turn { turn_right: true }),
+                _ => return Err(ParseError::new(move_line_idx, col_idx, move_line, format!("Unknown character in moves line: {}", c)))
+            };
+        }
+    }
+
+    if let Some(start) = cur_move_count_start {
+        moves.push(parse_move_count(start, move_line.len())?);
+    }
+
+    Ok((board, moves))
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<(Board, Vec<Move>)> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Board, Vec<Move>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(Board, Vec<Move>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((board, moves): &(Board, Vec<Move>)) -> Result<usize> {
+        Ok(get_password(&get_final_position(board, moves, false)))
+    }
+
+    fn part2((board, moves): &(Board, Vec<Move>)) -> Result<usize> {
+        check_cube_layout(board)?;
+        Ok(get_password(&get_final_position(board, moves, true)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let (board, moves) = read_input_file("../inputs/day22_example.txt").unwrap();
+        let final_pos = get_final_position(&board, &moves, false);
+        assert_eq!(final_pos.row + 1, 6);
+        assert_eq!(final_pos.column + 1, 8);
+        assert_eq!(final_pos.dir, Direction::Right);
+        assert_eq!(get_password(&final_pos), 6032);
+
+        let input = Day22::read_input("../inputs/day22_example.txt").unwrap();
+        assert_eq!(Day22::part1(&input).unwrap(), 6032);
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let e = parse_input("  ..#\n ...\n\n10R5X3\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 5, "10R5X3"));
+    }
+
+    // The cube layout of part 2 is hard-coded above (how ugly) and the layout differs in the example, so it is rejected
+    #[test]
+    fn cube_layout() {
+        let input = Day22::read_input("../inputs/day22_example.txt").unwrap();
+        let e = Day22::part2(&input).unwrap_err();
+        assert_eq!(e.to_string(), "Board does not fit the hardcoded cube layout (only the layout of the real puzzle inputs is supported)!");
+
+        // Walking around the smallest cube of the hardcoded layout with a side length of 2
+        let input = Day22::parse("  ....\n  ....\n  ..\n  ..\n....\n....\n..\n..\n\n10R3R10L7L2\n").unwrap();
+        assert!(check_cube_layout(&input.0).is_ok());
+        assert!(Day22::part2(&input).is_ok());
+        assert!(check_cube_layout(&Day22::parse("  ....\n  ....\n  ..\n  ..\n...#\n....\n..\n.\n\n1\n").unwrap().0).is_err());
+    }
+}
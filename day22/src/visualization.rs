@@ -0,0 +1,111 @@
+//! Frame-by-frame visualization of the walk over the board, where every frame is a single step or turn.
+
+use crate::{get_password, get_start_position, move_forward_one_step, Board, Field, Move, Position};
+use common::Visualization;
+use grid::{Direction, SparseGrid};
+
+#[derive(Clone)]
+pub struct PathVisualization<'a> {
+    board: &'a Board,
+    moves: &'a [Move],
+    cube_overflow: bool,
+    pos: Position,
+    move_idx: usize,
+    /// Number of steps already taken of the current forward move
+    steps_taken: usize,
+    /// Fields visited so far with the direction of the last visit
+    trail: SparseGrid<Direction>
+}
+
+impl<'a> PathVisualization<'a> {
+    pub fn new(board: &'a Board, moves: &'a [Move], cube_overflow: bool) -> Self {
+        let pos = get_start_position(board);
+        let trail = [((pos.column as i32, pos.row as i32), pos.dir)].into_iter().collect();
+        PathVisualization { board, moves, cube_overflow, pos, move_idx: 0, steps_taken: 0, trail }
+    }
+
+    fn next_move(&mut self) {
+        self.move_idx += 1;
+        self.steps_taken = 0;
+    }
+}
+
+impl Visualization for PathVisualization<'_> {
+    fn step(&mut self) -> bool {
+        // Forward moves that are blocked (right away or after some steps) do not produce another frame
+        while let Some(m) = self.moves.get(self.move_idx) {
+            match m {
+                Move::Forward { steps } => {
+                    let new_pos = match self.steps_taken < *steps {
+                        true => move_forward_one_step(self.board, &self.pos, self.cube_overflow),
+                        false => None
+                    };
+
+                    match new_pos {
+                        Some(p) => {
+                            self.pos = p;
+                            self.steps_taken += 1;
+                            if self.steps_taken == *steps {
+                                self.next_move();
+                            }
+                        },
+                        None => {
+                            self.next_move();
+                            continue;
+                        }
+                    }
+                },
+                Move::Turn { turn_right } => {
+                    self.pos.dir = if *turn_right { self.pos.dir.turn_right() } else { self.pos.dir.turn_left() };
+                    self.next_move();
+                }
+            }
+
+            self.trail.insert((self.pos.column as i32, self.pos.row as i32), self.pos.dir);
+            return true;
+        }
+
+        false
+    }
+
+    fn render(&self) -> String {
+        let cur_pos = (self.pos.column as i32, self.pos.row as i32);
+
+        grid::render_area((0, 0), (self.board.width() as i32 - 1, self.board.height() as i32 - 1), |pos| {
+            if pos == cur_pos {
+                return '@';
+            }
+
+            match (self.trail.get(pos), &self.board[pos]) {
+                (Some(Direction::Up), _) => '^',
+                (Some(Direction::Right), _) => '>',
+                (Some(Direction::Down), _) => 'v',
+                (Some(Direction::Left), _) => '<',
+                (None, Field::Empty) => '.',
+                (None, Field::Blocked) => '#',
+                (None, Field::OffMap) => ' '
+            }
+        })
+    }
+
+    fn status(&self) -> String {
+        format!("Move {} of {}, password {}", (self.move_idx + 1).min(self.moves.len()), self.moves.len(), get_password(&self.pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input_file;
+
+    #[test]
+    fn example() {
+        let (board, moves) = read_input_file("../inputs/day22_example.txt").unwrap();
+        let mut v = PathVisualization::new(&board, &moves, false);
+        assert!(v.render().starts_with("        @..#"));
+
+        while v.step() {}
+        assert_eq!(get_password(&v.pos), 6032);
+        assert!(v.render().starts_with("        >>v#"));
+    }
+}
@@ -0,0 +1,181 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{neighbours8, Direction, Pos, SparseGrid};
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{enabled, trace, Level};
+
+pub mod visualization;
+
+pub type Field = SparseGrid;
+
+/// Renders the elves with an empty border around them.
+fn render_field(f: &Field) -> String {
+    match f.bounds() {
+        Some((min, max)) => {
+            grid::render_area((min.0 - 1, min.1 - 1), (max.0 + 1, max.1 + 1), |pos| if f.contains(pos) { '#' } else { '.' })
+        },
+        None => String::new()
+    }
+}
+
+/// Emits the current state of the board as a trace event (only rendered if trace events are enabled).
+fn trace_field(f: &Field) {
+    if enabled!(Level::TRACE) {
+        trace!("Current state of board:\n{}", render_field(f));
+    }
+}
+
+pub fn count_empty_ground_tiles(f: &Field) -> usize {
+    let (min, max) = f.bounds().unwrap();
+    ((max.0 - min.0 + 1) * (max.1 - min.1 + 1)) as usize - f.len()
+}
+
+pub fn count_rounds_before_stable(field: &Field) -> usize {
+    let mut cur_field = field.clone();
+    let mut dir_check_order = vec!(Direction::Up, Direction::Down, Direction::Left, Direction::Right);
+    let mut n = 1;
+
+    loop {
+        let new_field = simulate_round(&cur_field, &dir_check_order);
+        if new_field == cur_field {
+            return n;
+        }
+
+        cur_field = new_field;
+
+        // Rotate list of directions
+        let dir = dir_check_order.remove(0);
+        dir_check_order.push(dir);
+
+        n += 1;
+    }
+}
+
+/// Returns the position the elf at the given position proposes to move to (which is its current position if it does not move).
+fn get_proposed_pos(f: &Field, pos: Pos, dir_check_order: &[Direction]) -> Pos {
+    // Check if elf does not have any adjacent elf and should not move
+    if !neighbours8(pos).any(|p| f.contains(p)) {
+        return pos;
+    }
+
+    for dir in dir_check_order {
+        // The field in the direction and its two diagonal neighbours have to be free
+        let target = dir.step(pos);
+        if !f.contains(target) && !f.contains(dir.turn_left().step(target)) && !f.contains(dir.turn_right().step(target)) {
+            return target;
+        }
+    }
+
+    // No move possible
+    pos
+}
+
+pub fn parse_input(input: &str) -> Result<Field, ParseError> {
+    let field = SparseGrid::parse(input, |_, c| match c {
+        '#' => Ok(Some(())),
+        '.' => Ok(None),
+        _ => Err(format!("Unknown input character: {}", c))
+    })?;
+
+    if field.is_empty() {
+        return Err(ParseError::at_end(input, "Map does not contain any elf!"));
+    }
+
+    Ok(field)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Field> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub fn simulate_n_rounds(field: &Field, n: usize) -> Field {
+    trace_field(field);
+
+    let mut cur_field = field.clone();
+    let mut dir_check_order = vec!(Direction::Up, Direction::Down, Direction::Left, Direction::Right);
+
+    for _ in 0..n {
+        cur_field = simulate_round(&cur_field, &dir_check_order);
+
+        // Rotate list of directions
+        let dir = dir_check_order.remove(0);
+        dir_check_order.push(dir);
+
+        trace_field(&cur_field);
+    }
+
+    cur_field
+}
+
+fn simulate_round(field: &Field, dir_check_order: &[Direction]) -> Field {
+    // Count how many elves want to move to a specific field
+    let mut target_count = HashMap::new();
+    for pos in field.positions() {
+        *target_count.entry(get_proposed_pos(field, pos, dir_check_order)).or_insert(0) += 1;
+    }
+
+    // Build new field where each elf is moved if the target count is only one
+    field
+        .positions()
+        .map(|pos| {
+            let new_pos = get_proposed_pos(field, pos, dir_check_order);
+            if target_count[&new_pos] == 1 { new_pos } else { pos }
+        })
+        .collect()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Field;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Field, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(elf_pos: &Field) -> Result<usize> {
+        Ok(count_empty_ground_tiles(&simulate_n_rounds(elf_pos, 10)))
+    }
+
+    fn part2(elf_pos: &Field) -> Result<usize> {
+        Ok(count_rounds_before_stable(elf_pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let elf_pos = Day23::read_input("../inputs/day23_example.txt").unwrap();
+        assert_eq!(Day23::part1(&elf_pos).unwrap(), 110);
+    }
+
+    #[test]
+    fn example_part1_small() {
+        let correct_end_state: Field = [(2, 5), (0, 2), (4, 1), (2, 0), (4, 3)].into_iter().collect();
+
+        let elf_pos = read_input_file("../inputs/day23_example_small.txt").unwrap();
+        let elf_pos = simulate_n_rounds(&elf_pos, 3);
+        assert_eq!(elf_pos, correct_end_state);
+    }
+
+    #[test]
+    fn example_part2() {
+        let elf_pos = Day23::read_input("../inputs/day23_example.txt").unwrap();
+        assert_eq!(Day23::part2(&elf_pos).unwrap(), 20);
+    }
+
+    #[test]
+    fn no_elves() {
+        for input in ["", "\n", "...\n...\n"] {
+            assert_eq!(parse_input(input).unwrap_err().message, "Map does not contain any elf!");
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Frame-by-frame visualization of the elves spreading out, where every frame is one round.
+
+use crate::{count_empty_ground_tiles, render_field, simulate_round, Field};
+use common::Visualization;
+use grid::Direction;
+
+#[derive(Clone)]
+pub struct ElfVisualization {
+    field: Field,
+    dir_check_order: Vec<Direction>,
+    round: usize,
+    /// Number of rounds to simulate (None simulates until no elf moves anymore)
+    max_rounds: Option<usize>,
+    stable: bool
+}
+
+impl ElfVisualization {
+    pub fn new(field: &Field, max_rounds: Option<usize>) -> Self {
+        let dir_check_order = vec!(Direction::Up, Direction::Down, Direction::Left, Direction::Right);
+        ElfVisualization { field: field.clone(), dir_check_order, round: 0, max_rounds, stable: false }
+    }
+}
+
+impl Visualization for ElfVisualization {
+    fn step(&mut self) -> bool {
+        if self.stable || self.max_rounds.is_some_and(|n| self.round >= n) {
+            return false;
+        }
+
+        // The round in which no elf moves is shown as a last frame (its number is the answer of the second part)
+        let new_field = simulate_round(&self.field, &self.dir_check_order);
+        self.stable = new_field == self.field;
+        self.field = new_field;
+        self.dir_check_order.rotate_left(1);
+        self.round += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        render_field(&self.field)
+    }
+
+    fn status(&self) -> String {
+        let rounds = match self.max_rounds {
+            Some(n) => format!("Round {} of {}", self.round, n),
+            None => format!("Round {}", self.round)
+        };
+        let empty_tiles = if self.field.is_empty() { 0 } else { count_empty_ground_tiles(&self.field) };
+
+        format!("{}, {} empty ground tiles{}", rounds, empty_tiles, if self.stable { ", no elf moved" } else { "" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day23;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let field = Day23::read_input("../inputs/day23_example.txt").unwrap();
+
+        let mut v = ElfVisualization::new(&field, Some(10));
+        while v.step() {}
+        assert_eq!(v.status(), "Round 10 of 10, 110 empty ground tiles");
+
+        let mut v = ElfVisualization::new(&field, None);
+        while v.step() {}
+        assert_eq!(v.round, 20);
+        assert!(v.status().ends_with(", no elf moved"));
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{neighbours4, Direction, Grid, Pos, SparseGrid};
+use std::iter::once;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::{enabled, trace, Level};
+
+pub mod visualization;
+
+pub struct Blizzard {
+    pos: Pos,
+    dir: Direction
+}
+
+/// Parsed input with the blizzard positions already precalculated for every round.
+pub struct Valley {
+    pub blizzards_at_round: Vec<SparseGrid>,
+    pub field_size: Pos,
+    pub start_pos: Pos,
+    pub end_pos: Pos
+}
+
+/// Renders the field with the blizzards and the current position.
+fn render_field(blizzards_at_pos: &SparseGrid, field_size: &Pos, start_pos: &Pos, end_pos: &Pos, cur_pos: &Pos) -> String {
+    grid::render_area((0, 0), (field_size.0 - 1, field_size.1 - 1), |(x, y)| {
+        if (x, y) == *cur_pos { 'X' }
+        else if blizzards_at_pos.contains((x, y)) { 'b' }
+        else if (x, y) == *start_pos || (x, y) == *end_pos { ' ' }
+        else if x == 0 || x == field_size.0 - 1 || y == 0 || y == field_size.1 - 1 { '#' }
+        else { ' ' }
+    })
+}
+
+/// Emits the field with the blizzards and the current position as a trace event (only rendered if trace events are enabled).
+fn trace_field(blizzards_at_pos: &SparseGrid, field_size: &Pos, start_pos: &Pos, end_pos: &Pos, cur_pos: &Pos) {
+    if enabled!(Level::TRACE) {
+        trace!("Current field state:\n{}", render_field(blizzards_at_pos, field_size, start_pos, end_pos, cur_pos));
+    }
+}
+
+/// Returns the number of steps necessary to reach the end position (or None if it cannot be reached).
+pub fn find_way(blizzards_at_pos: &[SparseGrid], field_size: &Pos, start_pos: &Pos, end_pos: &Pos, start_step_count: usize) -> Option<usize> {
+    let path = find_path(blizzards_at_pos, field_size, start_pos, end_pos, start_step_count)?;
+    for (pos, round) in &path.nodes {
+        trace_field(&blizzards_at_pos[*round], field_size, start_pos, end_pos, pos);
+    }
+
+    Some(start_step_count + path.cost)
+}
+
+/// Returns a shortest way to the end position as pairs of position and round (modulo the period of the blizzards), starting with the
+/// start position (or None if the end position cannot be reached).
+pub fn find_path(blizzards_at_pos: &[SparseGrid], field_size: &Pos, start_pos: &Pos, end_pos: &Pos, start_step_count: usize)
+    -> Option<search::Path<(Pos, usize), usize>> {
+    let period = blizzards_at_pos.len();
+
+    // BFS over the position and the round (modulo the period of the blizzards, since the state repeats afterwards), where we can wait
+    // or move to a neighbor in every step
+    let successors = |&(pos, round): &(Pos, usize)| {
+        let next_round = (round + 1) % period;
+        let blizzards = &blizzards_at_pos[next_round];
+
+        once(pos).chain(neighbours4(pos))
+            .filter(move |p| (p == start_pos || p == end_pos) || (p.0 >= 1 && p.0 <= field_size.0 - 2 && p.1 >= 1 && p.1 <= field_size.1 - 2))
+            .filter(move |p| !blizzards.contains(*p))
+            .map(move |p| (p, next_round))
+    };
+
+    search::bfs([(*start_pos, start_step_count % period)], successors, |(pos, _)| pos == end_pos)
+}
+
+/// Calculates the positions of blizzards for a total of X*Y rounds where X and Y is the row/colum size of the field (could also use the
+/// least common multiple here).
+pub fn precalc_blizzard_pos(blizzards: &mut [Blizzard], field_size: &Pos) -> Vec<SparseGrid> {
+    let mut res = vec!();
+
+    for _ in 0..(field_size.0 * field_size.1) {
+        let mut blizzard_pos = SparseGrid::new();
+
+        for b in blizzards.iter_mut() {
+            blizzard_pos.insert(b.pos, ());
+
+            // Move blizzard
+            match b.dir {
+                Direction::Up => {
+                    b.pos.1 = if b.pos.1 == 1 { field_size.1 - 2 } else { b.pos.1 - 1 };
+                },
+                Direction::Down => {
+                    b.pos.1 = if b.pos.1 == field_size.1 - 2 { 1 } else { b.pos.1 + 1 };
+                }
+                Direction::Left => {
+                    b.pos.0 = if b.pos.0 == 1 { field_size.0 - 2 } else { b.pos.0 - 1 };
+                }
+                Direction::Right => {
+                    b.pos.0 = if b.pos.0 == field_size.0 - 2 { 1 } else { b.pos.0 + 1 };
+                }
+            }
+        }
+
+        res.push(blizzard_pos);
+    }
+
+    res
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Blizzard>, Pos, Pos, Pos), ParseError> {
+    let valley = Grid::parse(input, |_, c| if "#.^v<>".contains(c) { Ok(c) } else { Err(format!("Unknown character in valley: {}", c)) })?;
+    if valley.height() < 2 {
+        return Err(ParseError::at_end(input, "Too few lines (expected at least the top and bottom wall)!"));
+    }
+
+    // Determine start and end position from the gaps in the top and bottom row
+    let bottom_row = valley.height() - 1;
+    let bottom_line = input.lines().nth(bottom_row).unwrap();
+    let start_x = valley.row(0).position(|c| *c == '.').ok_or_else(|| ParseError::in_line(0, input.lines().next().unwrap(), "Start position not found!"))?;
+    let end_x = valley.row(bottom_row).position(|c| *c == '.').ok_or_else(|| ParseError::in_line(bottom_row, bottom_line, "End position not found!"))?;
+    let start_pos = (start_x as i32, 0);
+    let end_pos = (end_x as i32, bottom_row as i32);
+    let field_size = (valley.width() as i32, valley.height() as i32);
+
+    // Parse information about blizzards
+    let blizzards = valley
+        .iter()
+        .filter_map(|(pos, c)| {
+            let dir = match c {
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                _ => return None  // Ignore walls and free fields
+            };
+            Some(Blizzard { pos, dir })
+        })
+        .collect();
+
+    Ok((blizzards, field_size, start_pos, end_pos))
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<(Vec<Blizzard>, Pos, Pos, Pos)> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Valley, ParseError> {
+        let (mut blizzards, field_size, start_pos, end_pos) = parse_input(input)?;
+        let blizzards_at_round = precalc_blizzard_pos(&mut blizzards, &field_size);
+        Ok(Valley { blizzards_at_round, field_size, start_pos, end_pos })
+    }
+
+    fn part1(v: &Valley) -> Result<usize> {
+        find_way(&v.blizzards_at_round, &v.field_size, &v.start_pos, &v.end_pos, 0).context("No way to the end found!")
+    }
+
+    fn part2(v: &Valley) -> Result<usize> {
+        // Go to the end, back to the start and then to the end again
+        let steps = find_way(&v.blizzards_at_round, &v.field_size, &v.start_pos, &v.end_pos, 0).context("No way to the end found!")?;
+        let steps = find_way(&v.blizzards_at_round, &v.field_size, &v.end_pos, &v.start_pos, steps).context("No way back found!")?;
+        find_way(&v.blizzards_at_round, &v.field_size, &v.start_pos, &v.end_pos, steps).context("No way to the end found!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (mut blizzards, field_size, start_pos, end_pos) = read_input_file("../inputs/day24_example.txt").unwrap();
+        let blizzards_at_round = precalc_blizzard_pos(&mut blizzards, &field_size);
+
+        // Part 1
+        assert_eq!(find_way( &blizzards_at_round, &field_size, &start_pos, &end_pos, 0), Some(18));
+
+        // Part 2
+        assert_eq!(find_way( &blizzards_at_round, &field_size, &end_pos, &start_pos, 18), Some(18 + 23));
+        assert_eq!(find_way( &blizzards_at_round, &field_size, &start_pos, &end_pos, 41), Some(41 + 13));
+
+        let valley = Day24::read_input("../inputs/day24_example.txt").unwrap();
+        assert_eq!(Day24::part1(&valley).unwrap(), 18);
+        assert_eq!(Day24::part2(&valley).unwrap(), 54);
+    }
+}
@@ -0,0 +1,70 @@
+//! Frame-by-frame visualization of the expedition through the blizzards, where every frame is one minute.
+
+use crate::{find_path, render_field, Valley};
+use common::Visualization;
+use grid::Pos;
+
+#[derive(Clone)]
+pub struct ExpeditionVisualization<'a> {
+    valley: &'a Valley,
+    /// Positions of the expedition with the round (modulo the period of the blizzards) for every minute
+    path: Vec<(Pos, usize)>,
+    minute: usize
+}
+
+impl<'a> ExpeditionVisualization<'a> {
+    /// Finds the way to the end (and back to the start and to the end again for the second part) upfront, returns None if there is none.
+    pub fn new(valley: &'a Valley, back_and_forth: bool) -> Option<Self> {
+        let (start, end) = (&valley.start_pos, &valley.end_pos);
+        let trips = if back_and_forth { vec!((start, end), (end, start), (start, end)) } else { vec!((start, end)) };
+        let mut path = vec!((*start, 0));
+
+        for (from, to) in trips {
+            let trip = find_path(&valley.blizzards_at_round, &valley.field_size, from, to, path.len() - 1)?;
+            path.extend(trip.nodes.into_iter().skip(1));
+        }
+
+        Some(ExpeditionVisualization { valley, path, minute: 0 })
+    }
+}
+
+impl Visualization for ExpeditionVisualization<'_> {
+    fn step(&mut self) -> bool {
+        if self.minute + 1 >= self.path.len() {
+            return false;
+        }
+
+        self.minute += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        let (pos, round) = &self.path[self.minute];
+        render_field(&self.valley.blizzards_at_round[*round], &self.valley.field_size, &self.valley.start_pos, &self.valley.end_pos, pos)
+    }
+
+    fn status(&self) -> String {
+        format!("Minute {} of {}", self.minute, self.path.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let valley = Day24::read_input("../inputs/day24_example.txt").unwrap();
+
+        for (back_and_forth, minutes) in [(false, 18), (true, 54)] {
+            let mut v = ExpeditionVisualization::new(&valley, back_and_forth).unwrap();
+            assert!(v.render().starts_with("#X####"));
+
+            while v.step() {}
+            assert_eq!(v.status(), format!("Minute {} of {}", minutes, minutes));
+            assert!(v.render().ends_with("####X#"));
+        }
+    }
+}
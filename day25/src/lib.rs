@@ -0,0 +1,129 @@
+use anyhow::{bail, Context, Result};
+use common::{ParseError, Solution};
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub fn decimal_to_snafu(mut d: i64) -> String {
+    // Determine current digit (if minus or double-minus, increase the number to compensate for it)
+    let cur_digit = match d.rem_euclid(5) {
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        3 => { d += 2; '=' },
+        4 => { d += 1; '-' },
+        _ => panic!("Invalid remainder value- this should never happen!")
+    };
+
+    d /= 5;
+    if d > 0 {
+        let mut s = decimal_to_snafu(d);
+        s.push(cur_digit);
+        s
+    } else {
+        cur_digit.to_string()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| match l.chars().position(|c| !"=-012".contains(c)) {
+            Some(col_idx) => Err(ParseError::new(line_idx, col_idx, l, "Unknown digit character in SNAFU number!")),
+            None => Ok(l.to_string())
+        })
+        .collect()
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<String>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub fn snafu_to_decimal(s: &str) -> i64 {
+    // Remove and parse last digit
+    match s.char_indices().next_back() {
+        Some((i, c)) => {
+            let digit_value = match c {
+                '=' => -2,
+                '-' => -1,
+                '0' => 0,
+                '1' => 1,
+                '2' => 2,
+                _ => panic!("Unknown digit character in SNAFU number!")
+            };
+            digit_value + snafu_to_decimal(&s[..i]) * 5
+        }
+        None => 0,
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<String>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(snafu_numbers: &Vec<String>) -> Result<String> {
+        Ok(decimal_to_snafu(snafu_numbers.iter().map(|s| snafu_to_decimal(s)).sum()))
+    }
+
+    fn part2(_snafu_numbers: &Vec<String>) -> Result<String> {
+        bail!("Day 25 has no second part of the puzzle!");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let snafu_numbers = read_input_file("../inputs/day25_example.txt").unwrap();
+        let decimal_numbers: Vec<i64> = snafu_numbers.iter().map(|s| snafu_to_decimal(s)).collect();
+        let mut it = decimal_numbers.iter();
+        assert_eq!(it.next(), Some(&1747));
+        assert_eq!(it.next(), Some(&906));
+        assert_eq!(it.next(), Some(&198));
+        assert_eq!(it.next(), Some(&11));
+        assert_eq!(it.next(), Some(&201));
+        assert_eq!(it.next(), Some(&31));
+        assert_eq!(it.next(), Some(&1257));
+        assert_eq!(it.next(), Some(&32));
+        assert_eq!(it.next(), Some(&353));
+        assert_eq!(it.next(), Some(&107));
+        assert_eq!(it.next(), Some(&7));
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.next(), Some(&37));
+        assert_eq!(it.next(), None);
+        assert_eq!(decimal_to_snafu(4890), "2=-1=0");
+        assert_eq!(Day25::part1(&snafu_numbers).unwrap(), "2=-1=0");
+    }
+
+    /// Canonical SNAFU numbers (no leading zeros) with up to 25 digits, so that they fit into an i64.
+    fn snafu_number() -> impl Strategy<Value = String> {
+        prop_oneof!(Just("0".to_string()), "[12][=\\-012]{0,24}")
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn decimal_round_trip(d in 0..i64::MAX / 4) {
+            let s = decimal_to_snafu(d);
+            prop_assert_eq!(snafu_to_decimal(&s), d);
+        }
+
+        #[test]
+        fn snafu_round_trip(s in snafu_number()) {
+            prop_assert_eq!(parse_input(&s).unwrap(), vec!(s.clone()));
+            prop_assert_eq!(decimal_to_snafu(snafu_to_decimal(&s)), s);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::{bail, ensure, Context, Result};
+use common::{ParseError, Solution};
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Number of elves in a group (part 2 of the puzzle).
+pub const GROUP_SIZE: usize = 3;
+
+/// Set of item types as 52-bit mask, where bit p - 1 stands for the item type with priority p (a-z: 1-26, A-Z: 27-52).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ItemSet(pub u64);
+
+impl ItemSet {
+    /// Set of all 52 item types.
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    /// Parses the items (all characters must be letters, otherwise the index of the first other character is returned as error).
+    fn from_items(items: &str) -> Result<ItemSet, usize> {
+        items
+            .chars()
+            .enumerate()
+            .try_fold(ItemSet::default(), |set, (idx, c)| get_char_priority(c).map(|p| ItemSet(set.0 | 1 << (p - 1))).ok_or(idx))
+    }
+
+    pub fn intersection(&self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the priorities of the item types in the set in ascending order.
+    pub fn priorities(&self) -> impl Iterator<Item = u32> + '_ {
+        (1..=52).filter(|p| self.0 & 1 << (p - 1) != 0)
+    }
+}
+
+#[derive(Debug)]
+pub struct Rucksack {
+    all: ItemSet,
+    comp1: ItemSet,
+    comp2: ItemSet
+}
+
+impl Rucksack {
+    pub fn get_duplicated_priorities_sum(&self) -> u32 {
+        self.comp1.intersection(self.comp2).priorities().sum()
+    }
+}
+
+/// Returns the priority of the badge, i.e. the only item type carried by all rucksacks of the group.
+pub fn find_common_badge_priority(group: &[Rucksack]) -> Result<u32> {
+    ensure!(!group.is_empty(), "Group does not contain any rucksack!");
+
+    let badges = group.iter().fold(ItemSet::ALL, |set, r| set.intersection(r.all));
+    match badges.len() {
+        1 => Ok(badges.priorities().next().unwrap()),
+        0 => bail!("No badge (item type carried by all {} elves) found for group!", group.len()),
+        _ => {
+            let items: String = badges.priorities().map(get_priority_char).collect();
+            bail!("Multiple badge candidates ({}) found for group, expected exactly one!", items)
+        }
+    }
+}
+
+/// Sums the badge priorities of the groups formed by group_size subsequent rucksacks.
+pub fn get_badge_priorities_sum(rucksack_list: &[Rucksack], group_size: usize) -> Result<u32> {
+    ensure!(group_size > 0, "Group size must be positive!");
+    ensure!(rucksack_list.len().is_multiple_of(group_size), "Number of rucksacks ({}) cannot be split into groups of {}!",
+        rucksack_list.len(), group_size);
+
+    rucksack_list
+        .chunks(group_size)
+        .enumerate()
+        .map(|(group_idx, group)| {
+            find_common_badge_priority(group)
+                .with_context(|| format!("Invalid group in lines {}-{}!", group_idx * group_size + 1, (group_idx + 1) * group_size))
+        })
+        .sum()
+}
+
+fn get_char_priority(c: char) -> Option<u32> {
+    if c.is_ascii_lowercase() {
+        Some(c as u32 - 'a' as u32 + 1)
+    } else if c.is_ascii_uppercase() {
+        Some(c as u32 - 'A' as u32 + 27)
+    } else {
+        None
+    }
+}
+
+fn get_priority_char(priority: u32) -> char {
+    if priority <= 26 {
+        (b'a' + priority as u8 - 1) as char
+    } else {
+        (b'A' + priority as u8 - 27) as char
+    }
+}
+
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| {
+            let all = ItemSet::from_items(l)
+                .map_err(|col_idx| ParseError::new(line_idx, col_idx, l, "Non-letter character not supported!"))?;
+
+            if !l.len().is_multiple_of(2) {
+                return Err(ParseError::in_line(line_idx, l, "Input line has odd length, so it cannot be split into two compartments!"));
+            }
+
+            // Both halves only consist of letters at this point
+            let (l1, l2) = l.split_at(l.len() / 2);
+            Ok(Rucksack { all, comp1: ItemSet::from_items(l1).unwrap(), comp2: ItemSet::from_items(l2).unwrap() })
+        })
+        .collect()
+}
+
+pub fn read_rucksack_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Rucksack>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_rucksacks(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(rucksack_list: &Vec<Rucksack>) -> Result<u32> {
+        Ok(rucksack_list
+            .iter()
+            .map(|r| r.get_duplicated_priorities_sum())
+            .sum())
+    }
+
+    fn part2(rucksack_list: &Vec<Rucksack>) -> Result<u32> {
+        get_badge_priorities_sum(rucksack_list, GROUP_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let rucksack_list = Day3::read_input("../inputs/day3_example.txt").unwrap();
+        assert_eq!(Day3::part1(&rucksack_list).unwrap(), 157);
+        assert_eq!(Day3::part2(&rucksack_list).unwrap(), 70);
+    }
+
+    #[test]
+    fn group_sizes() {
+        let rucksack_list = Day3::read_input("../inputs/day3_example.txt").unwrap();
+
+        // Single rucksacks carry several item types and the six example rucksacks do not share any item type
+        assert_eq!(get_badge_priorities_sum(&rucksack_list, 1).unwrap_err().root_cause().to_string(),
+            "Multiple badge candidates (cfghprstvwFJMW) found for group, expected exactly one!");
+        let err = get_badge_priorities_sum(&rucksack_list, 6).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid group in lines 1-6!");
+        assert_eq!(err.root_cause().to_string(), "No badge (item type carried by all 6 elves) found for group!");
+
+        let err = get_badge_priorities_sum(&rucksack_list, 4).unwrap_err();
+        assert_eq!(err.to_string(), "Number of rucksacks (6) cannot be split into groups of 4!");
+        assert!(get_badge_priorities_sum(&rucksack_list, 0).is_err());
+
+        // Two groups of two elves with J and Z as the only shared item types
+        let rucksack_list = parse_rucksacks("abJc\nJdef\nghZi\njZkl\n").unwrap();
+        assert_eq!(get_badge_priorities_sum(&rucksack_list, 2).unwrap(), 36 + 52);
+    }
+
+    #[test]
+    fn invalid_groups() {
+        let rucksack_list = parse_rucksacks("aabb\nccdd\naacc\nabcd\nbcda\ncdab\n").unwrap();
+        let err = get_badge_priorities_sum(&rucksack_list, 3).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid group in lines 1-3!");
+        assert_eq!(err.root_cause().to_string(), "No badge (item type carried by all 3 elves) found for group!");
+
+        let err = get_badge_priorities_sum(&rucksack_list[3..], 3).unwrap_err();
+        assert_eq!(err.root_cause().to_string(), "Multiple badge candidates (abcd) found for group, expected exactly one!");
+
+        assert_eq!(find_common_badge_priority(&[]).unwrap_err().to_string(), "Group does not contain any rucksack!");
+    }
+
+    #[test]
+    fn invalid_lines() {
+        let e = parse_rucksacks("abAB\nab1B\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 3, "Non-letter character not supported!"));
+
+        let e = parse_rucksacks("abc\n").unwrap_err();
+        assert_eq!((e.line, e.message.as_str()), (1, "Input line has odd length, so it cannot be split into two compartments!"));
+    }
+}
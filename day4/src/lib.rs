@@ -0,0 +1,140 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use regex::Regex;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub struct CleaningRange {
+    start: u32,
+    end: u32
+}
+
+impl CleaningRange {
+    pub fn contains(&self, other: &CleaningRange) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    pub fn overlaps(&self, other: &CleaningRange) -> bool {
+        // Beside the case that other strictly contains self, we just need to check two cases:
+        // Is other's start is within self or is other's end within self
+        other.contains(self) ||
+        (other.start >= self.start && other.start <= self.end) ||
+        (other.end >= self.start && other.end <= self.end)
+    }
+}
+
+pub struct CleaningPair {
+    pub r1: CleaningRange,
+    pub r2: CleaningRange
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<CleaningPair>, ParseError> {
+    let re = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| {
+            let cap = re.captures(l).ok_or_else(|| ParseError::in_line(line_idx, l, "Expected two ranges in the format a-b,c-d!"))?;
+            let nums: Vec<u32> = (1..=4).map(|n| {
+                let m = cap.get(n).unwrap();  // All capture groups are mandatory in the regex
+                m.as_str().parse().map_err(|_| ParseError::new(line_idx, m.start(), l, "Could not parse number!"))
+            }).collect::<Result<_, _>>()?;
+
+            Ok(CleaningPair {
+                r1: CleaningRange {
+                    start: nums[0],
+                    end: nums[1],
+                },
+                r2: CleaningRange {
+                    start: nums[2],
+                    end: nums[3]
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<CleaningPair>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<CleaningPair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<CleaningPair>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cps: &Vec<CleaningPair>) -> Result<usize> {
+        Ok(cps
+            .iter()
+            .filter(|cp| cp.r1.contains(&cp.r2) || cp.r2.contains(&cp.r1))
+            .count())
+    }
+
+    fn part2(cps: &Vec<CleaningPair>) -> Result<usize> {
+        Ok(cps
+            .iter()
+            .filter(|cp| cp.r1.overlaps(&cp.r2))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let cps = Day4::read_input("../inputs/day4_example.txt").unwrap();
+        assert_eq!(Day4::part1(&cps).unwrap(), 2);
+        assert_eq!(Day4::part2(&cps).unwrap(), 4);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let e = Day4::parse("2-4,6-8\n2-x,4-5\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "2-x,4-5"));
+    }
+
+    type Range = (u32, u32);
+
+    /// Section ranges within a small area, so that touching and overlapping ranges are common.
+    fn range() -> impl Strategy<Value = Range> {
+        (1..30u32, 0..10u32).prop_map(|(start, len)| (start, start + len))
+    }
+
+    /// Puzzle input with one "a-b,c-d" line per pair together with the generated ranges.
+    fn pairs_input() -> impl Strategy<Value = (String, Vec<(Range, Range)>)> {
+        prop::collection::vec((range(), range()), 1..20).prop_map(|pairs| {
+            let input = pairs.iter().map(|((a, b), (c, d))| format!("{}-{},{}-{}\n", a, b, c, d)).collect();
+            (input, pairs)
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn overlaps_is_symmetric((input, pairs) in pairs_input()) {
+            let cps = parse_input(&input).unwrap();
+            prop_assert_eq!(cps.len(), pairs.len());
+
+            for (cp, ((a, b), (c, d))) in cps.iter().zip(pairs) {
+                prop_assert_eq!((cp.r1.start, cp.r1.end, cp.r2.start, cp.r2.end), (a, b, c, d));
+                prop_assert_eq!(cp.r1.overlaps(&cp.r2), cp.r2.overlaps(&cp.r1));
+                prop_assert_eq!(cp.r1.overlaps(&cp.r2), a.max(c) <= b.min(d));
+                if cp.r1.contains(&cp.r2) || cp.r2.contains(&cp.r1) {
+                    prop_assert!(cp.r1.overlaps(&cp.r2));
+                }
+            }
+        }
+    }
+}
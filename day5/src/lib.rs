@@ -0,0 +1,243 @@
+use anyhow::{anyhow, Context, Result};
+use common::{ParseError, Solution};
+use regex::Regex;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub type Cargo = char;
+pub type CargoStack = Vec<Cargo>;
+
+pub struct CargoTransfer {
+    from: usize,
+    to: usize,
+    amount: usize
+}
+
+pub struct Input {
+    start_configuration: Vec<CargoStack>,
+    instructions: Vec<CargoTransfer>
+}
+
+enum ParserState {
+    StartConfiguration,
+    EmptyLine,
+    CargoTransfer
+}
+
+pub fn calc_final_configuration(input: &Input, move_all_crates_at_once: bool) -> Result<Vec<CargoStack>> {
+    let mut stacks = input.start_configuration.clone();
+
+    for instr in input.instructions.iter() {
+        if move_all_crates_at_once {
+            let from_len = stacks[instr.from - 1].len();
+            let mut transfer_elements = stacks[instr.from - 1].split_off(from_len - instr.amount);
+            stacks[instr.to - 1].append(&mut transfer_elements);
+        } else {
+            for _ in 0..instr.amount {
+                let c = stacks[instr.from - 1].pop().context("Stack ran empty during cargo transfer!")?;
+                stacks[instr.to - 1].push(c);
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+pub fn get_top_elements(config: &Vec<CargoStack>) -> Result<String> {
+    let mut res = String::new();
+
+    for cs in config {
+        let c = cs.last().ok_or_else(|| anyhow!("Cargo stack is empty in the final configuration!"))?;
+        res.push(*c);
+    }
+
+    Ok(res)
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    // We are assuming that the stack numbers are just one-digit numbers here (which is true for the provided input)
+    let re_start_configuration = Regex::new(r"\[[A-Z]\]|( {3,4})").unwrap();
+    let re_stack_numbers = Regex::new(r" \d ").unwrap();
+    let re_cargo_transfer = Regex::new(r"^move (?P<amount>\d+) from (?P<from>\d) to (?P<to>\d)$").unwrap();
+
+    let lines = input.lines();
+    let mut state = ParserState::StartConfiguration;
+    let mut config = vec!();
+    let mut instr = vec!();
+
+    for (line_idx, l) in lines.enumerate() {
+        match state {
+            ParserState::StartConfiguration => {
+                // Check if this line still contains a cargo
+                if l.contains('[') {
+                    // Collect all matches (cargo or empty field) into a vector
+                    let cap: Vec<regex::Match> = re_start_configuration.find_iter(l).collect();
+
+                    // Check number of stacks that we have read in this line
+                    let stack_count = cap.len();
+                    if config.is_empty() {
+                        config = vec![vec!(); stack_count];  // Create initial vector
+                    } else if config.len() != stack_count {
+                        return Err(ParseError::in_line(line_idx, l, format!(
+                            "We have already started reading the initial configuration, but now a line has {} instead of the initially set up {} stacks.",
+                            stack_count, config.len())));
+                    }
+
+                    for i in 0..stack_count {
+                        let c = cap[i].as_str();
+                        if !c.trim().is_empty() {
+                            if c.len() != 3 {
+                                return Err(ParseError::new(line_idx, cap[i].start(), l, "Cargo match does not follow [x] format!"));
+                            }
+
+                            // Inserting at the front is a terribly slow operation, but here it should be okay since the input is quite small (< 10 lines)
+                            config[i].insert(0, c.chars().nth(1).unwrap());
+                        }
+                    }
+                } else {
+                    // The input is not a valid "initial configuration line" anymore, so now we are expecting the numbers of the stacks in the input
+                    let stack_count = re_stack_numbers.find_iter(l).count();
+                    if stack_count != config.len() {
+                        return Err(ParseError::in_line(line_idx, l, format!(
+                            "Done with reading the initial configuration, but the numbering of the cargo stacks ({}) does not match the number of stacks we have read ({}).",
+                            stack_count, config.len())));
+                    }
+
+                    state = ParserState::EmptyLine;
+                }
+            },
+            ParserState::EmptyLine => {
+                if !l.is_empty() {
+                    return Err(ParseError::in_line(line_idx, l, "Expected an empty line after the stack numbers line!"));
+                }
+
+                state = ParserState::CargoTransfer;
+            },
+            ParserState::CargoTransfer => {
+                let caps = re_cargo_transfer
+                    .captures(l)
+                    .ok_or_else(|| ParseError::in_line(line_idx, l, "Could not parse cargo transfer line!"))?;
+
+                // All capture groups are mandatory in the regex, so only parsing the numbers can fail
+                let parse_number = |name: &str, max: usize| {
+                    let m = caps.name(name).unwrap();
+                    match m.as_str().parse() {
+                        Ok(n) if (1..=max).contains(&n) => Ok(n),
+                        _ => Err(ParseError::new(line_idx, m.start(), l, format!("Invalid \"{}\" number (must be in 1..={})!", name, max)))
+                    }
+                };
+
+                instr.push(CargoTransfer {
+                    from: parse_number("from", config.len())?,
+                    to: parse_number("to", config.len())?,
+                    amount: parse_number("amount", usize::MAX)?
+                });
+            }
+        }
+    }
+
+    Ok(Input {
+        start_configuration: config,
+        instructions: instr
+    })
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Input> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Input) -> Result<String> {
+        get_top_elements(&calc_final_configuration(input, false)?)
+    }
+
+    fn part2(input: &Input) -> Result<String> {
+        get_top_elements(&calc_final_configuration(input, true)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let input = Day5::read_input("../inputs/day5_example.txt").unwrap();
+        assert_eq!(Day5::part1(&input).unwrap(), "CMZ");
+        assert_eq!(Day5::part2(&input).unwrap(), "MCD");
+    }
+
+    /// Starting stacks (1 to 9, since the stack numbers are single digits) with at least one crate in total.
+    fn stacks() -> impl Strategy<Value = Vec<CargoStack>> {
+        prop::collection::vec(prop::collection::vec(prop::char::range('A', 'Z'), 0..8), 1..=9)
+            .prop_filter("No crates at all", |stacks| stacks.iter().any(|s| !s.is_empty()))
+    }
+
+    /// Puzzle input with a drawing of the starting stacks and transfers that never take more crates than a stack holds. The transfers
+    /// are generated from random seeds by tracking the stack heights. If single_crates is set, all transfers move exactly one crate.
+    fn crane_input(single_crates: bool) -> impl Strategy<Value = String> {
+        (stacks(), prop::collection::vec(any::<(usize, usize, usize)>(), 0..30)).prop_map(move |(stacks, seeds)| {
+            let max_height = stacks.iter().map(|s| s.len()).max().unwrap();
+            let mut input = String::new();
+
+            for row in (0..max_height).rev() {
+                let fields: Vec<String> = stacks.iter().map(|s| s.get(row).map_or("   ".into(), |c| format!("[{}]", c))).collect();
+                input += &(fields.join(" ") + "\n");
+            }
+            input += &((1..=stacks.len()).map(|n| format!(" {} ", n)).collect::<Vec<_>>().join(" ") + "\n\n");
+
+            let mut heights: Vec<usize> = stacks.iter().map(|s| s.len()).collect();
+            for (from_seed, to_seed, amount_seed) in seeds {
+                // Take from the next non-empty stack (there always is one, since crates are never removed)
+                let from = (0..heights.len()).map(|i| (from_seed + i) % heights.len()).find(|i| heights[*i] > 0).unwrap();
+                let to = to_seed % heights.len();
+                let amount = if single_crates { 1 } else { 1 + amount_seed % heights[from] };
+
+                heights[from] -= amount;
+                heights[to] += amount;
+                input += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+            }
+
+            input
+        })
+    }
+
+    fn sorted_crates(config: &[CargoStack]) -> Vec<Cargo> {
+        let mut crates: Vec<Cargo> = config.iter().flatten().copied().collect();
+        crates.sort_unstable();
+        crates
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn crane_modes_move_same_crates(input in crane_input(false)) {
+            let input = parse_input(&input).unwrap();
+            let one_by_one = calc_final_configuration(&input, false).unwrap();
+            let all_at_once = calc_final_configuration(&input, true).unwrap();
+
+            prop_assert_eq!(one_by_one.iter().map(|s| s.len()).collect::<Vec<_>>(), all_at_once.iter().map(|s| s.len()).collect::<Vec<_>>());
+            prop_assert_eq!(sorted_crates(&one_by_one), sorted_crates(&input.start_configuration));
+            prop_assert_eq!(sorted_crates(&all_at_once), sorted_crates(&input.start_configuration));
+        }
+
+        #[test]
+        fn crane_modes_agree_on_single_crates(input in crane_input(true)) {
+            let input = parse_input(&input).unwrap();
+            prop_assert_eq!(calc_final_configuration(&input, false).unwrap(), calc_final_configuration(&input, true).unwrap());
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::{anyhow, Result};
+use common::{ParseError, Solution};
+use std::collections::HashSet;
+
+pub fn get_marker_end(input: &str, window_size: usize) -> Result<usize> {
+    // Input is plain ASCII anyway
+    let b = input.as_bytes();
+
+    for (i, w) in b.windows(window_size).enumerate() {
+        let mut uniq: HashSet<u8> = HashSet::new();
+        uniq.extend(w);
+
+        if uniq.len() == window_size {
+            return Ok(i + window_size);  // Window does not contain a duplicate -> we are done
+        }
+    }
+
+    Err(anyhow!("No marker found!"))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &String) -> Result<usize> {
+        get_marker_end(input, 4)
+    }
+
+    fn part2(input: &String) -> Result<usize> {
+        get_marker_end(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example1() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(get_marker_end(input, 4).unwrap(), 7);
+        assert_eq!(get_marker_end(input, 14).unwrap(), 19);
+    }
+
+    #[test]
+    fn example2() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(get_marker_end(input, 4).unwrap(), 5);
+        assert_eq!(get_marker_end(input, 14).unwrap(), 23);
+    }
+
+    #[test]
+    fn example3() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(get_marker_end(input, 4).unwrap(), 6);
+        assert_eq!(get_marker_end(input, 14).unwrap(), 23);
+    }
+
+    #[test]
+    fn example4() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(get_marker_end(input, 4).unwrap(), 10);
+        assert_eq!(get_marker_end(input, 14).unwrap(), 29);
+    }
+
+    #[test]
+    fn example5() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(get_marker_end(input, 4).unwrap(), 11);
+        assert_eq!(get_marker_end(input, 14).unwrap(), 26);
+    }
+
+    #[test]
+    fn read_from_reader() {
+        let input = Day6::read_input_from("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".as_bytes()).unwrap();
+        assert_eq!(input, "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(Day6::part1(&input).unwrap(), 7);
+        assert_eq!(Day6::part2(&input).unwrap(), 19);
+    }
+}
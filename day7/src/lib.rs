@@ -0,0 +1,159 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub struct Dir {
+    subdirs: HashMap<String, Dir>,
+    files: HashMap<String, usize>
+}
+
+impl Dir {
+    /// Fails if the files take more than the total disk space or if no directory is large enough.
+    pub fn find_size_of_smallest_dir_to_delete(&self, total_disk_space: usize, required_disk_space: usize) -> Result<usize> {
+        let used_disk_space = self.get_total_dir_size();
+        let free_disk_space = total_disk_space
+            .checked_sub(used_disk_space)
+            .with_context(|| format!("Files take {} of only {} available disk space!", used_disk_space, total_disk_space))?;
+
+        // Deleting the smallest directory is enough if the required space is already free
+        let required_to_free = required_disk_space.saturating_sub(free_disk_space);
+        let mut dir_sizes = self.gather_all_dir_sizes();
+        dir_sizes.sort_unstable();
+        dir_sizes.into_iter().find(|s| *s >= required_to_free).context("No directory is large enough to be deleted!")
+    }
+
+    fn gather_all_dir_sizes(&self) -> Vec<usize> {
+        let mut dir_sizes = vec!(self.get_total_dir_size());
+        for subdir in self.subdirs.values() {
+            dir_sizes.extend(subdir.gather_all_dir_sizes());
+        }
+        dir_sizes
+    }
+
+    pub fn get_total_dir_size(&self) -> usize {
+        let size_subdirs: usize = self.subdirs.values().map(|d| d.get_total_dir_size()).sum();
+        let size_files: usize = self.files.values().sum();
+        size_subdirs + size_files
+    }
+
+    pub fn get_total_dir_size_if_below_threshold(&self, threshold: usize) -> usize {
+        let own_size = self.get_total_dir_size();
+        let subdir_sizes: usize = self.subdirs.values().map(|d| d.get_total_dir_size_if_below_threshold(threshold)).sum();
+        if own_size <= threshold {
+            own_size + subdir_sizes
+        } else {
+            subdir_sizes
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Dir, ParseError> {
+    let re_ls_output = Regex::new(r"^(dir|\d+) ([a-zA-Z.]+)$").unwrap();
+
+    let mut root = Dir {
+        subdirs: HashMap::new(),
+        files: HashMap::new()
+    };
+
+    let mut cur_path: Vec<String> = vec!();
+
+    for (line_idx, line) in input.lines().enumerate() {
+        let mut cur_dir = &mut root;
+        for path_element in cur_path.iter() {
+            cur_dir = cur_dir.subdirs
+                .get_mut(path_element)
+                .ok_or_else(|| ParseError::in_line(line_idx, line, format!("Subdirectory \"{}\" not found!", path_element)))?;
+        }
+
+        // If we do it that way, the parser does not have to be stateful (except storing the current dir)
+        if line == "$ cd .." {
+            if cur_path.is_empty() {
+                return Err(ParseError::in_line(line_idx, line, "Tried to cd .., but we are already in the root dir!"));
+            }
+
+            cur_path.pop();
+        } else if line == "$ cd /" {
+            cur_path = vec!();
+        } else if line.starts_with("$ cd ") {
+            let name: String = line.chars().skip(5).collect();
+            if !cur_dir.subdirs.contains_key(&name) {
+                return Err(ParseError::new(line_idx, 5, line, format!("Tried to switch into subdir {} which is not known!", name)));
+            }
+
+            cur_path.push(name);
+        } else if line == "$ ls" {
+            // ls command can be ignored: If a line does not match any of the cd/ls commands, it must be the output of a preceding ls command anyway
+        } else {
+            let c = re_ls_output.captures(line).ok_or_else(|| ParseError::in_line(line_idx, line, "Could not parse line as command or ls output!"))?;
+            let filesize_or_dir = c.get(1).unwrap().as_str();
+            let name = c.get(2).unwrap().as_str().to_string();
+
+            if filesize_or_dir == "dir" {
+                let dir = Dir {
+                    subdirs: HashMap::new(),
+                    files: HashMap::new()
+                };
+                cur_dir.subdirs.insert(name, dir);
+            } else {
+                let filesize = filesize_or_dir
+                    .parse()
+                    .map_err(|_| ParseError::in_line(line_idx, line, format!("Could not parse \"{}\" as number!", filesize_or_dir)))?;
+                cur_dir.files.insert(name, filesize);
+            }
+        }
+    }
+
+    Ok(root)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Dir> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Dir;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Dir, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(root: &Dir) -> Result<usize> {
+        Ok(root.get_total_dir_size_if_below_threshold(100000))
+    }
+
+    fn part2(root: &Dir) -> Result<usize> {
+        root.find_size_of_smallest_dir_to_delete(70000000, 30000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let root = Day7::read_input("../inputs/day7_example.txt").unwrap();
+        assert_eq!(root.get_total_dir_size(), 48381165);
+        assert_eq!(Day7::part1(&root).unwrap(), 95437);
+        assert_eq!(Day7::part2(&root).unwrap(), 24933642);
+    }
+
+    #[test]
+    fn disk_space() {
+        // Without any files, the root directory of size 0 is the smallest one
+        let root = parse_input("").unwrap();
+        assert_eq!(Day7::part1(&root).unwrap(), 0);
+        assert_eq!(Day7::part2(&root).unwrap(), 0);
+
+        let root = parse_input("$ cd /\n$ ls\n80000000 a.txt\n").unwrap();
+        assert_eq!(Day7::part2(&root).unwrap_err().to_string(), "Files take 80000000 of only 70000000 available disk space!");
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Pos};
+use std::fs::read_to_string;
+use std::path::Path;
+
+fn check_if_visible(grid: &Grid<u8>, pos: Pos, dir: Direction) -> bool {
+    grid.ray(pos, dir).all(|(_, h)| *h < grid[pos])
+}
+
+pub fn get_max_scenic_score(grid: &Grid<u8>) -> usize {
+    grid.positions()
+        .map(|pos| Direction::ALL.iter().map(|dir| get_scenic_score_for_dir(grid, pos, *dir)).product())
+        .max()
+        .unwrap_or(0)
+}
+
+fn get_scenic_score_for_dir(grid: &Grid<u8>, pos: Pos, dir: Direction) -> usize {
+    let own_height = grid[pos];
+    let tree_count = grid.ray(pos, dir).count();
+    let smaller_trees = grid.ray(pos, dir).take_while(|(_, h)| **h < own_height).count();
+
+    if smaller_trees < tree_count {
+        smaller_trees + 1
+    } else {
+        smaller_trees  // All trees until the border are smaller, so there is no higher tree to +1 for
+    }
+}
+
+pub fn get_visible_tree_count(grid: &Grid<u8>) -> usize {
+    grid.positions()
+        .filter(|pos| Direction::ALL.iter().any(|dir| check_if_visible(grid, *pos, *dir)))
+        .count()
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |_, c| c.to_digit(10).map(|d| d as u8).ok_or_else(|| "Could not parse digit!".to_string()))
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Grid<u8>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Result<usize> {
+        Ok(get_visible_tree_count(grid))
+    }
+
+    fn part2(grid: &Grid<u8>) -> Result<usize> {
+        Ok(get_max_scenic_score(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let grid = Day8::read_input("../inputs/day8_example.txt").unwrap();
+        assert_eq!(Day8::part1(&grid).unwrap(), 21);
+        assert_eq!(Day8::part2(&grid).unwrap(), 8);
+    }
+}
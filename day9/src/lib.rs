@@ -0,0 +1,137 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs::read_to_string;
+use std::path::Path;
+use tracing::trace;
+
+/// Maximum step count of a single move (the moves are expanded into single steps, so huge counts would exhaust the memory).
+const MAX_STEP_COUNT: usize = 100_000;
+
+#[derive(Clone)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+pub fn calc_visited_tail_fields(moves: &[Direction], tail_count: usize) -> HashSet<(i32, i32)> {
+    // First index is the head, all the other ones are the tails
+    let mut positions: Vec<(i32, i32)> = std::vec::from_elem((0, 0), tail_count + 1);
+    let mut visited_tail_fields = HashSet::new();
+
+    for dir in moves {
+        // Move head according to given movement direction
+        match dir {
+            Direction::Up => { positions[0].1 -= 1; }
+            Direction::Down => { positions[0].1 += 1; }
+            Direction::Left => { positions[0].0 -= 1; }
+            Direction::Right => { positions[0].0 += 1; }
+        }
+
+        // Move all tails from head to tail
+        for i in 1..=tail_count {
+            // Calculate delta to earlier tail (or head)
+            let delta = (positions[i - 1].0 - positions[i].0, positions[i - 1].1 - positions[i].1);
+
+            // Move tail according to movement of earlier tail (or head) if tail is too far away and needs to be moved
+            if delta.0.abs() > 1 || delta.1.abs() > 1 {
+                // Perform movement of tail segment where both axes are clamped at -1/1
+                let clamped_delta = (delta.0.signum(), delta.1.signum());
+                positions[i] = (positions[i].0 + clamped_delta.0, positions[i].1 + clamped_delta.1)
+            }
+        }
+
+        trace!(?positions, "Moved rope");
+
+        // Record position of final tail
+        visited_tail_fields.insert(*positions.iter().last().unwrap());
+    }
+
+    visited_tail_fields
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let re = Regex::new(r"^(U|D|L|R) (\d+)$").unwrap();
+    let mut res = vec!();
+
+    for (line_idx, l) in input.lines().enumerate() {
+        let c = re.captures(l).ok_or_else(|| ParseError::in_line(line_idx, l, "Expected a direction (U, D, L or R) and a step count!"))?;
+
+        let dir = match c.get(1).unwrap().as_str() {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => unreachable!()  // Regex only matches the four directions
+        };
+        let count: usize = match c.get(2).unwrap().as_str().parse() {
+            Ok(n) if n <= MAX_STEP_COUNT => n,
+            _ => return Err(ParseError::new(line_idx, 2, l, format!("Invalid step count (must be at most {})!", MAX_STEP_COUNT)))
+        };
+
+        for _ in 0..count {
+            res.push(dir.clone());
+        };
+    }
+
+    Ok(res)
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<Direction>> {
+    let input = read_to_string(&input_path).context("Could not read input file!")?;
+    Ok(parse_input(&input).map_err(|e| e.with_file(input_path))?)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Direction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(moves: &Vec<Direction>) -> Result<usize> {
+        Ok(calc_visited_tail_fields(moves, 1).len())
+    }
+
+    fn part2(moves: &Vec<Direction>) -> Result<usize> {
+        Ok(calc_visited_tail_fields(moves, 9).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_small() {
+        let moves = read_input_file("../inputs/day9_example1.txt").unwrap();
+
+        let visited_tail_fields = calc_visited_tail_fields(&moves, 1);
+        assert_eq!(visited_tail_fields.len(), 13);
+
+        let visited_tail_fields = calc_visited_tail_fields(&moves, 9);
+        assert_eq!(visited_tail_fields.len(), 1);
+    }
+
+    #[test]
+    fn example_large() {
+        let moves = read_input_file("../inputs/day9_example2.txt").unwrap();
+
+        let visited_tail_fields = calc_visited_tail_fields(&moves, 9);
+        assert_eq!(visited_tail_fields.len(), 36);
+    }
+
+    #[test]
+    fn huge_step_count() {
+        // Found by fuzzing (used to run out of memory)
+        let e = parse_input("R 3666666666666662\nRL\n").err().unwrap();
+        assert_eq!((e.line, e.column), (1, 3));
+    }
+}
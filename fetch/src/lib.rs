@@ -0,0 +1,171 @@
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::path::PathBuf;
+
+mod submit;
+#[cfg(test)]
+mod test_server;
+
+pub use submit::{SubmissionResult, Submitter, Verdict};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+
+/// Environment variable containing the session token (value of the "session" cookie of a logged in browser session).
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Environment variable to override the base URL (e.g. for a mirror or a local server in tests).
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+/// Provides the puzzle inputs: An input is read from the cache directory if it has already been stored there and otherwise downloaded
+/// once (using the session token) and stored in the cache directory as dayN_input.txt.
+pub struct InputProvider {
+    cache_dir: PathBuf,
+    base_url: String,
+    session: Option<String>
+}
+
+impl InputProvider {
+    pub fn new<P: Into<PathBuf>>(cache_dir: P) -> InputProvider {
+        InputProvider {
+            cache_dir: cache_dir.into(),
+            base_url: DEFAULT_BASE_URL.into(),
+            session: None
+        }
+    }
+
+    /// Creates a provider that takes the session token and (optionally) the base URL from the environment.
+    pub fn from_env<P: Into<PathBuf>>(cache_dir: P) -> InputProvider {
+        let mut provider = InputProvider::new(cache_dir);
+        if let Ok(base_url) = env::var(BASE_URL_ENV_VAR) {
+            provider = provider.with_base_url(base_url);
+        }
+        if let Ok(session) = env::var(SESSION_ENV_VAR) {
+            provider = provider.with_session(session.trim());
+        }
+        provider
+    }
+
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> InputProvider {
+        self.base_url = base_url.into().trim_end_matches('/').into();
+        self
+    }
+
+    pub fn with_session<S: Into<String>>(mut self, session: S) -> InputProvider {
+        self.session = Some(session.into());
+        self
+    }
+
+    pub fn has_session(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Returns the path where the input of the given day is stored in the cache directory (the file may not exist yet).
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}_input.txt", day))
+    }
+
+    pub fn is_cached(&self, day: u8) -> bool {
+        self.cached_path(day).exists()
+    }
+
+    /// Returns the path of the input file of the given day, downloading the input first if it is not in the cache yet.
+    pub fn input_path(&self, day: u8) -> Result<PathBuf> {
+        let path = self.cached_path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = self.download(day)?;
+
+        // Write to a temporary file first, so that an interrupted write does not leave a truncated input in the cache
+        create_dir_all(&self.cache_dir).with_context(|| format!("Could not create cache directory {}!", self.cache_dir.display()))?;
+        let tmp_path = path.with_extension("txt.part");
+        write(&tmp_path, input).with_context(|| format!("Could not write {}!", tmp_path.display()))?;
+        rename(&tmp_path, &path).with_context(|| format!("Could not move downloaded input to {}!", path.display()))?;
+
+        Ok(path)
+    }
+
+    /// Returns the input of the given day, downloading it first if it is not in the cache yet.
+    pub fn get_input(&self, day: u8) -> Result<String> {
+        let path = self.input_path(day)?;
+        read_to_string(&path).with_context(|| format!("Could not read input file {}!", path.display()))
+    }
+
+    fn download(&self, day: u8) -> Result<String> {
+        let session = match &self.session {
+            Some(s) => s,
+            None => bail!("Input for day {} is not in {} and no session token is set to download it (set {})!", day,
+                self.cache_dir.display(), SESSION_ENV_VAR)
+        };
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "github.com/chrismandery/advent-of-code-2022 input fetcher")
+            .call();
+
+        match response {
+            Ok(r) => r.into_string().with_context(|| format!("Could not read response from {}!", url)),
+            Err(ureq::Error::Status(code, r)) => {
+                let hint = match code {
+                    400 | 401 | 403 => " (is the session token valid?)",
+                    404 => " (is the puzzle already unlocked?)",
+                    _ => ""
+                };
+                let body = r.into_string().unwrap_or_default();
+                bail!("Downloading {} failed with status {}{}: {}", url, code, hint, body.lines().next().unwrap_or("").trim())
+            },
+            Err(e) => Err(e).with_context(|| format!("Could not download {}!", url))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_server::TestServer;
+
+    fn empty_cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-test-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn download_and_cache() {
+        let server = TestServer::start(|_| (200, "1000\n2000\n".into()));
+        let cache_dir = empty_cache_dir("download");
+        let provider = InputProvider::new(&cache_dir).with_base_url(&server.base_url).with_session("secret");
+
+        assert!(!provider.is_cached(1));
+        assert_eq!(provider.get_input(1).unwrap(), "1000\n2000\n");
+        assert_eq!(read_to_string(cache_dir.join("day1_input.txt")).unwrap(), "1000\n2000\n");
+
+        // Second access must be served from the cache
+        assert_eq!(provider.get_input(1).unwrap(), "1000\n2000\n");
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].head.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let server = TestServer::start(|_| (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into()));
+        let cache_dir = empty_cache_dir("failed");
+        let provider = InputProvider::new(&cache_dir).with_base_url(&server.base_url).with_session("expired");
+
+        let e = provider.get_input(5).unwrap_err();
+        assert!(e.to_string().contains("status 400"));
+        assert!(!provider.is_cached(5));
+        assert_eq!(server.request_count(), 1);
+
+        // Without session token, nothing is requested at all
+        let provider = InputProvider::new(&cache_dir).with_base_url(&server.base_url);
+        assert!(provider.get_input(5).unwrap_err().to_string().contains(SESSION_ENV_VAR));
+        assert_eq!(server.request_count(), 1);
+    }
+}
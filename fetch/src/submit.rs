@@ -0,0 +1,385 @@
+use crate::{BASE_URL_ENV_VAR, DEFAULT_BASE_URL, SESSION_ENV_VAR, YEAR};
+use anyhow::{bail, Context, Result};
+use common::Answer;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Why an answer has been rejected by the server.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    Wrong
+}
+
+/// Outcome of submitting an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubmissionResult {
+    Correct,
+
+    /// The server rejected the answer (and possibly asks to wait before the next submission).
+    Rejected { verdict: Verdict, wait: Option<Duration> },
+
+    /// The answer has not been submitted since the ledger shows that it is wrong (same value or beyond a known too high/too low answer).
+    KnownWrong { verdict: Verdict },
+
+    /// The server is (or the ledger knows that it is) not accepting answers yet.
+    RateLimited { wait: Duration },
+
+    /// The part has already been solved (the correct answer is included if it is known from the ledger).
+    AlreadySolved { answer: Option<String> }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong")
+        }
+    }
+}
+
+impl Display for SubmissionResult {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            SubmissionResult::Correct => write!(f, "Correct answer!"),
+            SubmissionResult::Rejected { verdict, wait: Some(wait) } => {
+                write!(f, "Answer is {} (next submission possible in {} s)", verdict, wait.as_secs())
+            },
+            SubmissionResult::Rejected { verdict, wait: None } => write!(f, "Answer is {}", verdict),
+            SubmissionResult::KnownWrong { verdict } => write!(f, "Not submitted, the ledger shows that this answer is {}", verdict),
+            SubmissionResult::RateLimited { wait } => write!(f, "Not accepted, wait {} s before submitting again", wait.as_secs()),
+            SubmissionResult::AlreadySolved { answer: Some(answer) } => write!(f, "Already solved (correct answer was {})", answer),
+            SubmissionResult::AlreadySolved { answer: None } => write!(f, "Already solved")
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct RejectedAnswer {
+    answer: String,
+    verdict: Verdict
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+struct PartRecord {
+    #[serde(default)]
+    correct: Option<String>,
+
+    #[serde(default)]
+    rejected: Vec<RejectedAnswer>
+}
+
+impl PartRecord {
+    /// Checks whether the answer is known to be wrong: Either it has been rejected before, or it is a number that is not lower than an
+    /// answer that was too high (resp. not higher than an answer that was too low).
+    fn known_verdict(&self, answer: &str) -> Option<Verdict> {
+        if let Some(r) = self.rejected.iter().find(|r| r.answer == answer) {
+            return Some(r.verdict);
+        }
+
+        let n: i128 = answer.parse().ok()?;
+        self.rejected.iter().find_map(|r| match (r.verdict, r.answer.parse::<i128>()) {
+            (Verdict::TooHigh, Ok(high)) if n >= high => Some(Verdict::TooHigh),
+            (Verdict::TooLow, Ok(low)) if n <= low => Some(Verdict::TooLow),
+            _ => None
+        })
+    }
+}
+
+/// Local record of all submissions, stored as JSON.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Ledger {
+    /// Keyed by "day-part", e.g. "12-2"
+    #[serde(default)]
+    parts: BTreeMap<String, PartRecord>,
+
+    /// Unix timestamp (in seconds) before which the server will not accept another answer
+    #[serde(default)]
+    blocked_until: Option<u64>
+}
+
+/// Submits answers to the server and keeps a ledger of the results, so that a wrong answer is never submitted twice and the rate limit
+/// is respected.
+pub struct Submitter {
+    ledger_path: PathBuf,
+    base_url: String,
+    session: Option<String>
+}
+
+impl Submitter {
+    pub fn new<P: Into<PathBuf>>(ledger_path: P) -> Submitter {
+        Submitter {
+            ledger_path: ledger_path.into(),
+            base_url: DEFAULT_BASE_URL.into(),
+            session: None
+        }
+    }
+
+    /// Creates a submitter that takes the session token and (optionally) the base URL from the environment.
+    pub fn from_env<P: Into<PathBuf>>(ledger_path: P) -> Submitter {
+        let mut submitter = Submitter::new(ledger_path);
+        if let Ok(base_url) = env::var(BASE_URL_ENV_VAR) {
+            submitter = submitter.with_base_url(base_url);
+        }
+        if let Ok(session) = env::var(SESSION_ENV_VAR) {
+            submitter = submitter.with_session(session.trim());
+        }
+        submitter
+    }
+
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> Submitter {
+        self.base_url = base_url.into().trim_end_matches('/').into();
+        self
+    }
+
+    pub fn with_session<S: Into<String>>(mut self, session: S) -> Submitter {
+        self.session = Some(session.into());
+        self
+    }
+
+    fn load_ledger(&self) -> Result<Ledger> {
+        if !self.ledger_path.exists() {
+            return Ok(Ledger::default());
+        }
+
+        let content = read_to_string(&self.ledger_path).with_context(|| format!("Could not read ledger {}!", self.ledger_path.display()))?;
+        serde_json::from_str(&content).with_context(|| format!("Could not parse ledger {}!", self.ledger_path.display()))
+    }
+
+    fn save_ledger(&self, ledger: &Ledger) -> Result<()> {
+        if let Some(dir) = self.ledger_path.parent() {
+            create_dir_all(dir).with_context(|| format!("Could not create ledger directory {}!", dir.display()))?;
+        }
+
+        // Write to a temporary file first, so that an interrupted write does not leave a truncated ledger behind
+        let mut tmp_path = self.ledger_path.clone().into_os_string();
+        tmp_path.push(".part");
+        let tmp_path = PathBuf::from(tmp_path);
+        write(&tmp_path, serde_json::to_string_pretty(ledger)?).with_context(|| format!("Could not write {}!", tmp_path.display()))?;
+        rename(&tmp_path, &self.ledger_path).with_context(|| format!("Could not move ledger to {}!", self.ledger_path.display()))
+    }
+
+    /// Submits the answer for the given day and part (unless the ledger already shows that this is pointless).
+    pub fn submit(&self, day: u8, part: u8, answer: &Answer) -> Result<SubmissionResult> {
+        let answer = match answer {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => s.clone(),
+            Answer::Grid(_) => bail!("Answers drawn as an image have to be read and submitted by hand!")
+        };
+
+        let mut ledger = self.load_ledger()?;
+        let key = format!("{}-{}", day, part);
+        let record = ledger.parts.entry(key.clone()).or_default();
+
+        // Check the ledger before bothering the server
+        if let Some(correct) = &record.correct {
+            return Ok(SubmissionResult::AlreadySolved { answer: Some(correct.clone()) });
+        }
+        if let Some(verdict) = record.known_verdict(&answer) {
+            return Ok(SubmissionResult::KnownWrong { verdict });
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if let Some(blocked_until) = ledger.blocked_until.filter(|t| *t > now) {
+            return Ok(SubmissionResult::RateLimited { wait: Duration::from_secs(blocked_until - now) });
+        }
+
+        let result = parse_response(&self.post_answer(day, part, &answer)?)?;
+
+        // Update ledger
+        let record = ledger.parts.get_mut(&key).unwrap();
+        match &result {
+            SubmissionResult::Correct => record.correct = Some(answer),
+            SubmissionResult::Rejected { verdict, .. } => record.rejected.push(RejectedAnswer { answer, verdict: *verdict }),
+            _ => {}
+        }
+        ledger.blocked_until = match &result {
+            SubmissionResult::Rejected { wait: Some(wait), .. } | SubmissionResult::RateLimited { wait } => Some(now + wait.as_secs()),
+            _ => None
+        };
+        self.save_ledger(&ledger)?;
+
+        Ok(result)
+    }
+
+    fn post_answer(&self, day: u8, part: u8, answer: &str) -> Result<String> {
+        let session = match &self.session {
+            Some(s) => s,
+            None => bail!("No session token is set to submit answers (set {})!", SESSION_ENV_VAR)
+        };
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "github.com/chrismandery/advent-of-code-2022 answer submission")
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        match response {
+            Ok(r) => r.into_string().with_context(|| format!("Could not read response from {}!", url)),
+            Err(ureq::Error::Status(code, _)) => bail!("Submitting to {} failed with status {} (is the session token valid?)", url, code),
+            Err(e) => Err(e).with_context(|| format!("Could not submit to {}!", url))
+        }
+    }
+}
+
+/// Extracts the message from the response page (the text of the <article> element, without any markup).
+fn get_response_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses how long to wait from messages like "You have 1m 30s left to wait." or "Please wait one minute before trying again."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let left_to_wait_regex = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(caps) = left_to_wait_regex.captures(text) {
+        let minutes: u64 = caps.get(1).map(|m| m.as_str().parse().unwrap()).unwrap_or(0);
+        let seconds: u64 = caps[2].parse().unwrap();
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let wait_minutes_regex = Regex::new(r"(?i)wait (one|\d+) minutes?").unwrap();
+    let caps = wait_minutes_regex.captures(text)?;
+    let minutes: u64 = if &caps[1] == "one" { 1 } else { caps[1].parse().unwrap() };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+fn parse_response(page: &str) -> Result<SubmissionResult> {
+    let text = get_response_text(page);
+
+    if text.contains("That's the right answer") {
+        Ok(SubmissionResult::Correct)
+    } else if text.contains("not the right answer") {
+        let verdict = if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        Ok(SubmissionResult::Rejected { verdict, wait: parse_wait(&text) })
+    } else if text.contains("answer too recently") {
+        Ok(SubmissionResult::RateLimited { wait: parse_wait(&text).unwrap_or(Duration::from_secs(60)) })
+    } else if text.contains("already complete it") {
+        Ok(SubmissionResult::AlreadySolved { answer: None })
+    } else {
+        bail!("Unknown response from server: {}", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+
+    fn article(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text)
+    }
+
+    fn empty_ledger_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-submit-test-{}-{}.json", std::process::id(), name));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn responses() {
+        let too_high = article("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full \
+            input data. Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>");
+        assert_eq!(parse_response(&too_high).unwrap(),
+            SubmissionResult::Rejected { verdict: Verdict::TooHigh, wait: Some(Duration::from_secs(60)) });
+
+        let too_recently = article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+            You have 1m 5s left to wait.");
+        assert_eq!(parse_response(&too_recently).unwrap(), SubmissionResult::RateLimited { wait: Duration::from_secs(65) });
+
+        let done = article("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_response(&done).unwrap(), SubmissionResult::AlreadySolved { answer: None });
+
+        assert!(parse_response("<html>Something else</html>").is_err());
+    }
+
+    #[test]
+    fn ledger_prevents_resubmission() {
+        let server = TestServer::start(|r| {
+            let body = if r.body.contains("answer=42") {
+                article("That's the right answer!  You are one gold star closer to saving your vacation.")
+            } else if r.body.contains("answer=100") {
+                article("That's not the right answer; your answer is too high.")
+            } else {
+                article("That's not the right answer; your answer is too low.")
+            };
+            (200, body)
+        });
+
+        let ledger_path = empty_ledger_path("ledger");
+        let submitter = Submitter::new(&ledger_path).with_base_url(&server.base_url).with_session("secret");
+
+        let too_high = SubmissionResult::Rejected { verdict: Verdict::TooHigh, wait: None };
+        assert_eq!(submitter.submit(3, 1, &Answer::Number(100)).unwrap(), too_high);
+        assert_eq!(submitter.submit(3, 1, &Answer::Number(100)).unwrap(), SubmissionResult::KnownWrong { verdict: Verdict::TooHigh });
+        assert_eq!(submitter.submit(3, 1, &Answer::Number(150)).unwrap(), SubmissionResult::KnownWrong { verdict: Verdict::TooHigh });
+        assert_eq!(server.request_count(), 1);
+
+        let request = server.requests.lock().unwrap()[0].clone();
+        assert!(request.head.starts_with("POST /2022/day/3/answer HTTP/1.1\r\n"));
+        assert_eq!(request.header("Cookie"), Some("session=secret"));
+        assert_eq!(request.body, "level=1&answer=100");
+
+        assert_eq!(submitter.submit(3, 1, &Answer::Number(10)).unwrap(), SubmissionResult::Rejected { verdict: Verdict::TooLow, wait: None });
+        assert_eq!(submitter.submit(3, 1, &Answer::Number(42)).unwrap(), SubmissionResult::Correct);
+        assert_eq!(submitter.submit(3, 1, &Answer::Number(43)).unwrap(), SubmissionResult::AlreadySolved { answer: Some("42".into()) });
+        assert_eq!(server.request_count(), 3);
+
+        // Other part is tracked separately
+        assert_eq!(submitter.submit(3, 2, &Answer::Number(100)).unwrap(), too_high);
+        assert_eq!(server.request_count(), 4);
+    }
+
+    #[test]
+    fn rate_limit_is_respected() {
+        let server = TestServer::start(|_| (200, article("You gave an answer too recently. You have 30s left to wait.")));
+        let submitter = Submitter::new(empty_ledger_path("rate_limit")).with_base_url(&server.base_url).with_session("secret");
+
+        assert_eq!(submitter.submit(1, 1, &Answer::Number(1)).unwrap(), SubmissionResult::RateLimited { wait: Duration::from_secs(30) });
+        assert!(matches!(submitter.submit(1, 1, &Answer::Number(1)).unwrap(), SubmissionResult::RateLimited { .. }));
+        assert_eq!(server.request_count(), 1);
+    }
+
+    #[test]
+    fn ledger_directory_is_created() {
+        let server = TestServer::start(|_| (200, article("That's the right answer!")));
+        let dir = env::temp_dir().join(format!("aoc-submit-test-{}-dir", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let ledger_path = dir.join("answers").join("ledger.json");
+        let submitter = Submitter::new(&ledger_path).with_base_url(&server.base_url).with_session("secret");
+
+        assert_eq!(submitter.submit(5, 1, &Answer::Number(7)).unwrap(), SubmissionResult::Correct);
+        assert!(ledger_path.exists());
+        assert!(!dir.join("answers").join("ledger.json.part").exists());
+        assert_eq!(submitter.submit(5, 1, &Answer::Number(8)).unwrap(), SubmissionResult::AlreadySolved { answer: Some("7".into()) });
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
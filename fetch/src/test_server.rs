@@ -0,0 +1,62 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Request received by the test server (head contains the request line and all headers).
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub head: String,
+    pub body: String
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().find_map(|l| {
+            let (n, v) = l.split_once(':')?;
+            if n.eq_ignore_ascii_case(name) { Some(v.trim()) } else { None }
+        })
+    }
+}
+
+/// Minimal local HTTP server for testing the client: Every request is recorded and answered with the status and body returned by the
+/// handler.
+pub struct TestServer {
+    pub base_url: String,
+    pub requests: Arc<Mutex<Vec<Request>>>
+}
+
+impl TestServer {
+    pub fn start<F: Fn(&Request) -> (u16, String) + Send + 'static>(handler: F) -> TestServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec!()));
+
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(&stream);
+                let mut head = String::new();
+                while reader.read_line(&mut head).unwrap() > 2 && !head.ends_with("\r\n\r\n") {}
+
+                let mut request = Request { head, body: String::new() };
+                let content_length = request.header("Content-Length").and_then(|v| v.parse().ok()).unwrap_or(0);
+                let mut body = vec!(0; content_length);
+                reader.read_exact(&mut body).unwrap();
+                request.body = String::from_utf8(body).unwrap();
+
+                let (status, response_body) = handler(&request);
+                recorded.lock().unwrap().push(request);
+
+                write!(stream, "HTTP/1.1 {} Test\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, response_body.len(),
+                    response_body).unwrap();
+            }
+        });
+
+        TestServer { base_url, requests }
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
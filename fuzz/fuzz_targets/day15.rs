@@ -0,0 +1,29 @@
+#![no_main]
+
+use common::Solution;
+use day15::Day15;
+use libfuzzer_sys::fuzz_target;
+
+/// Coordinates at the edges of the i64 range, which random text hardly ever reaches.
+const EXTREMES: [i64; 6] = [i64::MIN, i64::MIN + 1, -1, 1, i64::MAX - 1, i64::MAX];
+
+/// Picks one of the extreme coordinates or the raw value (the selector decides, so that both occur about equally often).
+fn coordinate((selector, raw): (u8, i64)) -> i64 {
+    EXTREMES.get(selector as usize % (2 * EXTREMES.len())).copied().unwrap_or(raw)
+}
+
+// The parser must reject any malformed input with an error instead of panicking, which is also checked for well-formed sensor
+// descriptions with extreme coordinates
+fuzz_target!(|data: (&str, Vec<[(u8, i64); 4]>)| {
+    let (input, sensors) = data;
+    let _ = Day15::parse(input);
+
+    let input: String = sensors
+        .into_iter()
+        .map(|s| {
+            let [sensor_x, sensor_y, beacon_x, beacon_y] = s.map(coordinate);
+            format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sensor_x, sensor_y, beacon_x, beacon_y)
+        })
+        .collect();
+    let _ = Day15::parse(&input);
+});
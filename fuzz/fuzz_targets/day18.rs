@@ -0,0 +1,10 @@
+#![no_main]
+
+use common::Solution;
+use day18::Day18;
+use libfuzzer_sys::fuzz_target;
+
+// The parser must reject any malformed input with an error instead of panicking
+fuzz_target!(|input: &str| {
+    let _ = Day18::parse(input);
+});
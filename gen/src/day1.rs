@@ -0,0 +1,14 @@
+//! Calorie lists: The size is the number of elves.
+
+use itertools::Itertools;
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    // The second part needs at least three elves
+    (0..size.max(3))
+        .map(|_| {
+            let item_count = rng.gen_range(1..=15);
+            (0..item_count).map(|_| format!("{}\n", rng.gen_range(1000..=60000))).collect::<String>()
+        })
+        .join("\n")
+}
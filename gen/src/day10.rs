@@ -0,0 +1,27 @@
+//! CPU instructions: The size is the number of instructions (more are generated if needed to draw the whole CRT image).
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut res = String::new();
+    let mut instruction_count = 0;
+    let mut cycle_count = 0;
+    let mut x: i32 = 1;
+
+    while instruction_count < size || cycle_count < 240 {
+        // Keep the sprite (mostly) on the screen by moving it towards a random column
+        let value = (rng.gen_range(0..40) - x).clamp(-15, 15);
+        if value == 0 || rng.gen_bool(0.4) {
+            res.push_str("noop\n");
+            cycle_count += 1;
+        } else {
+            res.push_str(&format!("addx {}\n", value));
+            cycle_count += 2;
+            x += value;
+        }
+
+        instruction_count += 1;
+    }
+
+    res
+}
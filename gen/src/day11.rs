@@ -0,0 +1,110 @@
+//! Monkey notes: The size is the number of items in total (distributed over eight monkeys, each starting with at least one item).
+
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const MONKEY_COUNT: usize = 8;
+const DIVISORS: [u64; MONKEY_COUNT] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    target_true: usize,
+    target_false: usize
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square
+}
+
+/// Checks whether the worry levels stay within 64 bits during the 20 rounds of the first part (where they are not reduced by a modulo).
+fn fits_first_part(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u128>> = monkeys.iter().map(|m| m.items.iter().map(|i| *i as u128).collect()).collect();
+
+    for _ in 0..20 {
+        for (i, m) in monkeys.iter().enumerate() {
+            for worry in std::mem::take(&mut items[i]) {
+                let worry = match m.operation {
+                    Operation::Add(n) => worry + n as u128,
+                    Operation::Mul(n) => worry * n as u128,
+                    Operation::Square => worry * worry
+                };
+                if worry > u64::MAX as u128 {
+                    return false;
+                }
+
+                let worry = worry / 3;
+                items[if worry % m.divisor as u128 == 0 { m.target_true } else { m.target_false }].push(worry);
+            }
+        }
+    }
+
+    true
+}
+
+fn random_monkeys(rng: &mut impl Rng, size: usize) -> Vec<Monkey> {
+    let mut items: Vec<Vec<u64>> = vec![vec!(); MONKEY_COUNT];
+    for i in 0..size.max(MONKEY_COUNT) {
+        let monkey = if i < MONKEY_COUNT { i } else { rng.gen_range(0..MONKEY_COUNT) };
+        items[monkey].push(rng.gen_range(50..=99));
+    }
+
+    // Like in the real input, exactly one monkey squares the worry level
+    let squaring_monkey = rng.gen_range(0..MONKEY_COUNT);
+    let mut divisors = DIVISORS;
+    divisors.shuffle(rng);
+
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(m, items)| {
+            let operation = if m == squaring_monkey {
+                Operation::Square
+            } else if rng.gen_bool(0.3) {
+                Operation::Mul(rng.gen_range(2..=19))
+            } else {
+                Operation::Add(rng.gen_range(1..=8))
+            };
+
+            // Items are never thrown to the monkey itself and the two targets are different
+            let target_true = (m + rng.gen_range(1..MONKEY_COUNT)) % MONKEY_COUNT;
+            let mut target_false = target_true;
+            while target_false == target_true || target_false == m {
+                target_false = rng.gen_range(0..MONKEY_COUNT);
+            }
+
+            Monkey { items, operation, divisor: divisors[m], target_true, target_false }
+        })
+        .collect()
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    // The solver uses 64-bit worry levels (like the real inputs, the generated ones must not overflow them)
+    let monkeys = loop {
+        let monkeys = random_monkeys(rng, size);
+        if fits_first_part(&monkeys) {
+            break monkeys;
+        }
+    };
+
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let operation = match m.operation {
+                Operation::Add(n) => format!("+ {}", n),
+                Operation::Mul(n) => format!("* {}", n),
+                Operation::Square => "* old".to_string()
+            };
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i, m.items.iter().join(", "), operation, m.divisor, m.target_true, m.target_false)
+        })
+        .join("\n")
+}
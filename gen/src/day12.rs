@@ -0,0 +1,63 @@
+//! Heightmaps: The size is the width of the map (the height is a quarter of it, but at least five rows).
+
+use grid::{Grid, Pos};
+use rand::seq::IteratorRandom;
+use rand::Rng;
+
+/// Returns a random simple path from the start with at least 26 positions (enough to climb from elevation a to z), which is the deepest
+/// branch of a randomized depth-first search.
+fn random_path(rng: &mut impl Rng, width: usize, height: usize, start: Pos) -> Vec<Pos> {
+    loop {
+        let mut parents: Grid<Option<Option<Pos>>> = Grid::filled(width, height, None);
+        parents[start] = Some(None);
+        let mut stack = vec!(start);
+        let mut deepest = (1, start);
+
+        while let Some(&pos) = stack.last() {
+            if stack.len() > deepest.0 {
+                deepest = (stack.len(), pos);
+            }
+
+            match parents.neighbours4(pos).filter(|n| parents[*n].is_none()).choose(rng) {
+                Some(next) => {
+                    parents[next] = Some(Some(pos));
+                    stack.push(next);
+                },
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        if deepest.0 >= 26 {
+            let mut path = vec!(deepest.1);
+            while let Some(Some(parent)) = parents[*path.last().unwrap()] {
+                path.push(parent);
+            }
+            path.reverse();
+            return path;
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let width = size.max(10);
+    let height = (size / 4).max(5);
+    let start = (0, rng.gen_range(0..height as i32));
+
+    // All positions that are not on the path get random elevations (so there may be shortcuts), along the path the elevation increases
+    // by at most one per step
+    let mut map = Grid::filled(width, height, 'a');
+    for pos in map.positions().collect::<Vec<_>>() {
+        map[pos] = rng.gen_range('a'..='z');
+    }
+
+    let path = random_path(rng, width, height, start);
+    for (i, pos) in path.iter().enumerate() {
+        map[*pos] = (b'a' + (25 * i / (path.len() - 1)) as u8) as char;
+    }
+    map[start] = 'S';
+    map[*path.last().unwrap()] = 'E';
+
+    map.render(|c| *c) + "\n"
+}
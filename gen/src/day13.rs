@@ -0,0 +1,17 @@
+//! Pairs of packets: The size is the number of pairs.
+
+use itertools::Itertools;
+use rand::Rng;
+
+/// Returns a random list with up to five elements, which are numbers or lists themselves (up to the given nesting depth).
+fn random_list(rng: &mut impl Rng, depth: u32) -> String {
+    let elements = (0..rng.gen_range(0..=5))
+        .map(|_| if depth > 0 && rng.gen_bool(0.3) { random_list(rng, depth - 1) } else { rng.gen_range(0..=10).to_string() })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", elements.iter().join(","))
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{}\n{}\n", random_list(rng, 4), random_list(rng, 4))).join("\n")
+}
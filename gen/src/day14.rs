@@ -0,0 +1,33 @@
+//! Rock structure scans: The size is the number of rock paths (the cave gets deeper with more paths).
+
+use itertools::Itertools;
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let max_depth = 10 + size as i32;
+
+    (0..size)
+        .map(|_| {
+            // Paths alternate between horizontal and vertical lines and never reach the sand source at 500,0
+            let mut pos = (rng.gen_range(470..=530), rng.gen_range(2..=max_depth));
+            let mut points = vec!(pos);
+            let mut horizontal = rng.gen_bool(0.5);
+
+            for _ in 0..rng.gen_range(1..=5) {
+                let len = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                if horizontal {
+                    pos.0 += len;
+                } else {
+                    pos.1 = (pos.1 + len).clamp(2, max_depth);
+                }
+
+                if points.last() != Some(&pos) {
+                    points.push(pos);
+                }
+                horizontal = !horizontal;
+            }
+
+            points.iter().map(|(x, y)| format!("{},{}", x, y)).join(" -> ") + "\n"
+        })
+        .collect()
+}
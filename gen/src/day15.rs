@@ -0,0 +1,67 @@
+//! Sensor reports: The size is the number of additional sensors besides the four sensors that cover everything except the distress beacon.
+//!
+//! In coordinates rotated by 45 degrees (u = x + y, v = x - y), the area covered by a sensor is a square. The distress beacon is hidden
+//! at a random position p in the search area of the second part: Two sensors cover everything left (u < p.u) and right (u > p.u) of it
+//! and two sensors cover the column u = p.u above (v > p.v) and below (v < p.v) it. Each closest beacon lies on the far side of the
+//! square of its sensor, so it is not within the range of any other of these four sensors. The additional sensors are placed randomly
+//! within the search area with a range that does not reach the distress beacon.
+
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Search area of the second part (as hardcoded in the solver).
+const MAX_COORD: i64 = 4000000;
+
+type Sensor = ((i64, i64), (i64, i64));
+
+/// Returns the sensor whose (rotated) square has the center (u, v). u and v must have the same parity, so that the center is a valid
+/// position. The beacon is placed on the side of the square in the given direction (-1 or 1 for the u or v axis).
+fn rotated_sensor(rng: &mut impl Rng, u: i64, v: i64, radius: i64, du: i64, dv: i64) -> Sensor {
+    let sensor = ((u + v) / 2, (u - v) / 2);
+    let a = rng.gen_range(0..=radius);
+    let offset = match (du, dv) {
+        (-1, 0) => (-a, -(radius - a)),
+        (1, 0) => (a, radius - a),
+        (0, 1) => (a, -(radius - a)),
+        _ => (-a, radius - a)
+    };
+
+    (sensor, (sensor.0 + offset.0, sensor.1 + offset.1))
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let p = (rng.gen_range(1..MAX_COORD), rng.gen_range(1..MAX_COORD));
+    let (pu, pv) = (p.0 + p.1, p.0 - p.1);
+
+    // The search area spans u in 0..=2 * MAX_COORD and v in -MAX_COORD..=MAX_COORD. u and v of a center must have the same parity: The
+    // side sensors are centered at v = 0 or v = 1 and the radius of the column sensors (centered at pv +- (1 + radius)) must be odd.
+    let side_radius = MAX_COORD + 1;
+    let column_radius = MAX_COORD + 1 + (MAX_COORD % 2);
+    let side_v = |u: i64| u.rem_euclid(2);
+    let mut sensors = vec!(
+        rotated_sensor(rng, pu - 1 - side_radius, side_v(pu - 1 - side_radius), side_radius, -1, 0),
+        rotated_sensor(rng, pu + 1 + side_radius, side_v(pu + 1 + side_radius), side_radius, 1, 0),
+        rotated_sensor(rng, pu, pv + 1 + column_radius, column_radius, 0, 1),
+        rotated_sensor(rng, pu, pv - 1 - column_radius, column_radius, 0, -1)
+    );
+
+    while sensors.len() < size + 4 {
+        let sensor = (rng.gen_range(0..=MAX_COORD), rng.gen_range(0..=MAX_COORD));
+        let dist_to_p = (sensor.0 - p.0).abs() + (sensor.1 - p.1).abs();
+        if dist_to_p < 2 {
+            continue;
+        }
+
+        let radius = rng.gen_range(1..dist_to_p.min(MAX_COORD / 4));
+        let dx = rng.gen_range(-radius..=radius);
+        let dy = (radius - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+        sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+    }
+
+    sensors.shuffle(rng);
+    sensors
+        .iter()
+        .map(|((sx, sy), (bx, by))| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sx, sy, bx, by))
+        .join("")
+}
@@ -0,0 +1,48 @@
+//! Valve scans: The size is the number of valves (at most 676), a quarter of them have a flow rate.
+
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::BTreeSet;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+
+    // The valve AA (where we start) is always the first one and has no flow rate
+    let mut names: Vec<String> = (b'A'..=b'Z').flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char))).collect();
+    names[1..].shuffle(rng);
+    names.truncate(count);
+
+    let mut flow_rates = vec![0; count];
+    for i in rand::seq::index::sample(rng, count - 1, (count / 4).max(1)) {
+        flow_rates[i + 1] = rng.gen_range(3..=25);
+    }
+
+    // A random spanning tree makes sure that all valves can be reached, additional tunnels create cycles
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for i in 1..count {
+        connect(i, rng.gen_range(0..i));
+    }
+    for _ in 0..count / 2 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    let mut lines: Vec<String> = (0..count)
+        .map(|i| {
+            let targets = tunnels[i].iter().map(|t| &names[*t]).join(", ");
+            if tunnels[i].len() == 1 {
+                format!("Valve {} has flow rate={}; tunnel leads to valve {}\n", names[i], flow_rates[i], targets)
+            } else {
+                format!("Valve {} has flow rate={}; tunnels lead to valves {}\n", names[i], flow_rates[i], targets)
+            }
+        })
+        .collect();
+    lines.shuffle(rng);
+    lines.concat()
+}
@@ -0,0 +1,7 @@
+//! Jet patterns: The size is the number of jets (at least one).
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| if rng.gen_bool(0.5) { '<' } else { '>' }).chain(['\n']).collect()
+}
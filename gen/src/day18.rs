@@ -0,0 +1,12 @@
+//! Lava droplet scans: The size is the number of cubes, which fill about half of a cube-shaped area (so there are air pockets).
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let edge = ((2 * size) as f64).cbrt().ceil().max(2.0) as i32;
+
+    let mut positions: Vec<(i32, i32, i32)> = (1..=edge).flat_map(|x| (1..=edge).flat_map(move |y| (1..=edge).map(move |z| (x, y, z)))).collect();
+    positions.shuffle(rng);
+    positions.iter().take(size.max(1)).map(|(x, y, z)| format!("{},{},{}\n", x, y, z)).collect()
+}
@@ -0,0 +1,13 @@
+//! Robot blueprints: The size is the number of blueprints.
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                id, rng.gen_range(2..=4), rng.gen_range(2..=4), rng.gen_range(2..=4), rng.gen_range(5..=20), rng.gen_range(2..=4), rng.gen_range(5..=20))
+        })
+        .collect()
+}
@@ -0,0 +1,7 @@
+//! Strategy guides: The size is the number of rounds.
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{} {}\n", rng.gen_range('A'..='C'), rng.gen_range('X'..='Z'))).collect()
+}
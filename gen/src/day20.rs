@@ -0,0 +1,9 @@
+//! Encrypted files: The size is the number of numbers (exactly one of which is zero).
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (1..size.max(2)).map(|_| rng.gen_range(1..=10000) * if rng.gen_bool(0.5) { 1 } else { -1 }).collect();
+    numbers.insert(rng.gen_range(0..=numbers.len()), 0);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
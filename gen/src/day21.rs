@@ -0,0 +1,80 @@
+//! Monkey expression trees: The size is the number of monkeys.
+//!
+//! The tree is built top-down from the values the monkeys have to yell, so that all numbers are positive (and do not overflow) and all
+//! divisions are exact. Both operands of the root monkey yield the same value, so the number that the human yells is also the answer of
+//! the second part.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+struct TreeBuilder<'a, R: Rng> {
+    rng: &'a mut R,
+    used_names: HashSet<String>,
+    lines: Vec<String>
+}
+
+impl<R: Rng> TreeBuilder<'_, R> {
+    fn new_name(&mut self) -> String {
+        loop {
+            let name: String = (0..4).map(|_| self.rng.gen_range('a'..='z')).collect();
+            if name != "root" && name != "humn" && self.used_names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// Adds a subtree of (about) the given number of monkeys that yields the value and returns the name of its root monkey. If with_humn
+    /// is set, one of the leaves is the human.
+    fn add_subtree(&mut self, value: i64, size: usize, with_humn: bool) -> String {
+        if size <= 1 {
+            let name = if with_humn { "humn".to_string() } else { self.new_name() };
+            self.lines.push(format!("{}: {}\n", name, value));
+            return name;
+        }
+
+        // Choose the operation and the values of the operands
+        let divisors: Vec<i64> = (2..=20).filter(|d| value % d == 0).collect();
+        let (a, operator, b) = match self.rng.gen_range(0..4) {
+            0 if value >= 2 => {
+                let a = self.rng.gen_range(1..value);
+                (a, '+', value - a)
+            },
+            1 if !divisors.is_empty() => {
+                let a = *divisors.choose(self.rng).unwrap();
+                (a, '*', value / a)
+            },
+            2 if value < 1_000_000_000_000 => {
+                let b = self.rng.gen_range(2..=10);
+                (value * b, '/', b)
+            },
+            _ => {
+                let b = self.rng.gen_range(1..=100);
+                (value + b, '-', b)
+            }
+        };
+
+        let name = self.new_name();
+        let left_size = self.rng.gen_range(1..size.max(3) - 1);
+        let humn_left = self.rng.gen_bool(0.5);
+        let left = self.add_subtree(a, left_size, with_humn && humn_left);
+        let right = self.add_subtree(b, size - 1 - left_size, with_humn && !humn_left);
+        self.lines.push(format!("{}: {} {} {}\n", name, left, operator, right));
+        name
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let value = rng.gen_range(1..=1000);
+    let mut builder = TreeBuilder { rng, used_names: HashSet::new(), lines: vec!() };
+
+    // The root monkey and two subtrees of (nearly) the same size
+    let subtree_size = size.saturating_sub(1).max(2) / 2;
+    let humn_left = builder.rng.gen_bool(0.5);
+    let left = builder.add_subtree(value, subtree_size, humn_left);
+    let right = builder.add_subtree(value, subtree_size, !humn_left);
+    builder.lines.push(format!("root: {} + {}\n", left, right));
+
+    builder.lines.shuffle(builder.rng);
+    builder.lines.concat()
+}
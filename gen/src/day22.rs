@@ -0,0 +1,38 @@
+//! Monkey maps: The size is the side length of the cube faces. The faces are arranged in the same net as in the real inputs (which is
+//! the layout hardcoded in the solver for the second part).
+
+use rand::Rng;
+
+/// Positions of the faces in the net (row and column in units of the side length).
+const FACES: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let side_len = size.max(2);
+    let mut res = String::new();
+
+    for row in 0..4 * side_len {
+        let face_columns: Vec<usize> = FACES.iter().filter(|f| f.0 == row / side_len).map(|f| f.1).collect();
+        let width = (face_columns.iter().max().unwrap() + 1) * side_len;
+
+        for column in 0..width {
+            res.push(if !face_columns.contains(&(column / side_len)) {
+                ' '
+            } else if (row, column) != (0, side_len) && rng.gen_bool(0.1) {
+                '#'
+            } else {
+                '.'  // The start position (leftmost open tile in the top row) is always open
+            });
+        }
+        res.push('\n');
+    }
+
+    // The path alternates between numbers of tiles to move and turns, it starts and ends with a number
+    res.push('\n');
+    res += &rng.gen_range(1..=side_len).to_string();
+    for _ in 0..20 * side_len {
+        res.push(if rng.gen_bool(0.5) { 'L' } else { 'R' });
+        res += &rng.gen_range(1..=side_len).to_string();
+    }
+    res.push('\n');
+    res
+}
@@ -0,0 +1,15 @@
+//! Elf positions: The size is the side length of the (square) scan (at least one), about half of the positions are elves (at least one).
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut scan: Vec<Vec<char>> = (0..size).map(|_| (0..size).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }).collect()).collect();
+
+    // Small scans may not contain any elf by chance
+    if scan.iter().flatten().all(|c| *c == '.') {
+        scan[rng.gen_range(0..size)][rng.gen_range(0..size)] = '#';
+    }
+
+    scan.into_iter().map(|row| row.into_iter().chain(['\n']).collect::<String>()).collect()
+}
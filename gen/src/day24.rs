@@ -0,0 +1,30 @@
+//! Blizzard basins: The size is the width of the valley (without the walls), its height is a fifth of the width (but at least three).
+//!
+//! Blizzards are placed on about a third of the fields, which leaves enough free fields to always find a way through the valley. Like
+//! in the real inputs, there are no vertical blizzards in the columns of the entrance and the exit, since they would leave the valley
+//! there.
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let width = size.max(3);
+    let height = (width / 5).max(3);
+
+    let mut res = format!("#.{}\n", "#".repeat(width));
+    for _ in 0..height {
+        res.push('#');
+        for x in 0..width {
+            let vertical_allowed = x != 0 && x != width - 1;
+            res.push(match rng.gen_range(0..12) {
+                0 => '<',
+                1 => '>',
+                2 if vertical_allowed => '^',
+                3 if vertical_allowed => 'v',
+                _ => '.'
+            });
+        }
+        res.push_str("#\n");
+    }
+    res += &format!("{}.#\n", "#".repeat(width));
+    res
+}
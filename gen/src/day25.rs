@@ -0,0 +1,24 @@
+//! SNAFU fuel requirements: The size is the number of numbers.
+
+use rand::Rng;
+
+fn to_snafu(mut n: i64) -> String {
+    let mut digits = vec!();
+    while n > 0 {
+        let (digit, carry) = match n % 5 {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            _ => ('-', 1)
+        };
+        digits.push(digit);
+        n = n / 5 + carry;
+    }
+
+    digits.iter().rev().collect()
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size).map(|_| to_snafu(rng.gen_range(1..=1_000_000_000_000)) + "\n").collect()
+}
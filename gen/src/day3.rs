@@ -0,0 +1,41 @@
+//! Rucksacks: The size is the number of elf groups (three lines each).
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut res = String::new();
+
+    for _ in 0..size {
+        // The badge is carried by all three elves of the group. The remaining 51 item types are split into three disjoint pools, so that
+        // no other item type is shared within the group. Each pool contains the item type that is in both compartments of the rucksack
+        // and two disjoint sets of item types that are only in one of the compartments.
+        let mut items: Vec<char> = ITEMS.chars().collect();
+        items.shuffle(rng);
+        let badge = items[0];
+
+        for pool in items[1..].chunks(17) {
+            let shared = pool[0];
+            let (only_first, only_second) = pool[1..].split_at(8);
+            let compartment_size = rng.gen_range(2..=16);
+
+            let mut comp1 = vec!(shared, badge);
+            comp1.extend((2..compartment_size).map(|_| *only_first.choose(rng).unwrap()));
+            let mut comp2 = vec!(shared);
+            comp2.extend((1..compartment_size).map(|_| *only_second.choose(rng).unwrap()));
+            comp1.shuffle(rng);
+            comp2.shuffle(rng);
+
+            if rng.gen_bool(0.5) {
+                std::mem::swap(&mut comp1, &mut comp2);
+            }
+
+            res.extend(comp1.into_iter().chain(comp2));
+            res.push('\n');
+        }
+    }
+
+    res
+}
@@ -0,0 +1,17 @@
+//! Section assignment pairs: The size is the number of pairs.
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+        (start, rng.gen_range(start..=99))
+    };
+
+    (0..size)
+        .map(|_| {
+            let ((a, b), (c, d)) = (range(), range());
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
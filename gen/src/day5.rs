@@ -0,0 +1,36 @@
+//! Crate stacks and rearrangement procedures: The size is the number of transfers (there are always nine stacks).
+
+use itertools::Itertools;
+use rand::Rng;
+
+const STACK_COUNT: usize = 9;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let stacks: Vec<Vec<char>> = (0..STACK_COUNT).map(|_| (0..rng.gen_range(2..=8)).map(|_| rng.gen_range('A'..='Z')).collect()).collect();
+    let max_height = stacks.iter().map(|s| s.len()).max().unwrap();
+    let mut res = String::new();
+
+    // Drawing of the stacks (top row first)
+    for row in (0..max_height).rev() {
+        // Empty fields at the end are not trimmed, since the parser determines the number of stacks from each line
+        res += &stacks.iter().map(|s| s.get(row).map_or("   ".into(), |c| format!("[{}]", c))).join(" ");
+        res.push('\n');
+    }
+    res += &((1..=STACK_COUNT).map(|n| format!(" {} ", n)).join(" ") + "\n\n");
+
+    // Transfers always leave at least one crate on the stack, so that every stack has a top crate in the end. Since there are at least
+    // two crates per stack in total, there always is a stack with more than one crate to take from.
+    let mut heights: Vec<usize> = stacks.iter().map(|s| s.len()).collect();
+    for _ in 0..size {
+        let candidates: Vec<usize> = (0..STACK_COUNT).filter(|i| heights[*i] > 1).collect();
+        let from = candidates[rng.gen_range(0..candidates.len())];
+        let to = (from + rng.gen_range(1..STACK_COUNT)) % STACK_COUNT;
+        let amount = rng.gen_range(1..heights[from]);
+
+        heights[from] -= amount;
+        heights[to] += amount;
+        res += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+    }
+
+    res
+}
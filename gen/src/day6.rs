@@ -0,0 +1,17 @@
+//! Datastreams: The size is the length of the datastream.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    // The start-of-message marker (14 different characters) is placed at the end, the characters before it are taken from a smaller
+    // alphabet so that they cannot contain a start-of-message marker
+    let prefix_len = size.saturating_sub(14);
+    let mut res: String = (0..prefix_len).map(|_| rng.gen_range('a'..='j')).collect();
+
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    marker.shuffle(rng);
+    res.extend(&marker[..14]);
+    res.push('\n');
+    res
+}
@@ -0,0 +1,84 @@
+//! Terminal output of browsing a file system: The size is the number of directories (besides the root directory).
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+struct Dir {
+    name: String,
+    subdirs: Vec<usize>,
+    files: Vec<(String, u32)>
+}
+
+/// Returns a random name (only letters and dots are allowed by the parser) that is not used yet in the directory.
+fn unique_name(rng: &mut impl Rng, used: &mut HashSet<String>, with_extension: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.gen_range(1..=8)).map(|_| rng.gen_range('a'..='z')).collect();
+        if with_extension {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range('a'..='z')));
+        }
+
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Writes the commands to list the directory and then to visit every subdirectory (in the order of the listing).
+fn write_dir(rng: &mut impl Rng, dirs: &[Dir], index: usize, res: &mut String) {
+    res.push_str("$ ls\n");
+
+    let mut listing: Vec<String> = dirs[index].subdirs.iter().map(|d| format!("dir {}\n", dirs[*d].name)).collect();
+    listing.extend(dirs[index].files.iter().map(|(name, size)| format!("{} {}\n", size, name)));
+    listing.shuffle(rng);
+    res.extend(listing);
+
+    for d in dirs[index].subdirs.iter() {
+        res.push_str(&format!("$ cd {}\n", dirs[*d].name));
+        write_dir(rng, dirs, *d, res);
+        res.push_str("$ cd ..\n");
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut dirs: Vec<Dir> = vec!();
+    let mut used_names: Vec<HashSet<String>> = vec!();
+
+    // Every directory is added to a random directory that has been created before, the root directory has index 0
+    for index in 0..=size {
+        let mut name = "/".to_string();
+        if index > 0 {
+            let parent = rng.gen_range(0..index);
+            name = unique_name(rng, &mut used_names[parent], false);
+            dirs[parent].subdirs.push(index);
+        }
+
+        // File sizes are spread over several orders of magnitude (the relative sizes matter, they are scaled below)
+        let mut names = HashSet::new();
+        let files = (0..rng.gen_range(0..=4))
+            .map(|_| {
+                let with_extension = rng.gen_bool(0.7);
+                (unique_name(rng, &mut names, with_extension), 10f64.powf(rng.gen_range(2.0..6.5)) as u32)
+            })
+            .collect();
+        used_names.push(names);
+        dirs.push(Dir { name, subdirs: vec!(), files });
+    }
+
+    // Scale the file sizes so that the used disk space is between 40000000 and 70000000 (like in the real inputs, the second part needs
+    // a directory to be deleted, but the disk is not overfull)
+    if dirs[0].files.is_empty() {
+        let name = unique_name(rng, &mut used_names[0], true);
+        dirs[0].files.push((name, 1));
+    }
+    let total_size: u64 = dirs.iter().flat_map(|d| d.files.iter()).map(|f| f.1 as u64).sum();
+    let target_size = rng.gen_range(42000000..=68000000);
+    for f in dirs.iter_mut().flat_map(|d| d.files.iter_mut()) {
+        f.1 = ((f.1 as u64 * target_size / total_size) as u32).max(1);
+    }
+
+    let mut res = "$ cd /\n".to_string();
+    write_dir(rng, &dirs, 0, &mut res);
+    res
+}
@@ -0,0 +1,8 @@
+//! Tree height maps: The size is the side length of the (square) map (at least one).
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size).map(|_| (0..size).map(|_| rng.gen_range('0'..='9')).chain(['\n']).collect::<String>()).collect()
+}
@@ -0,0 +1,7 @@
+//! Rope head motions: The size is the number of motions.
+
+use rand::Rng;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{} {}\n", ["U", "D", "L", "R"][rng.gen_range(0..4)], rng.gen_range(1..=20))).collect()
+}
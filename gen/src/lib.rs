@@ -0,0 +1,118 @@
+//! Generators for random puzzle inputs: Every generator produces a valid input in the format of the real input of its day, so that the
+//! solvers can be tested and benchmarked with inputs beyond the example and the real input.
+
+use anyhow::{bail, Result};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// Generates an input for the given day. The same seed and size always yield the same input. What the size controls depends on the
+/// day (e.g. the number of lines or the side length of a map, see the dayN modules), default_size returns sizes similar to the real inputs.
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<String> {
+    // ChaCha is used (instead of StdRng) since its output for a seed is guaranteed to stay the same across versions of rand
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let input = match day {
+        1 => day1::generate(&mut rng, size),
+        2 => day2::generate(&mut rng, size),
+        3 => day3::generate(&mut rng, size),
+        4 => day4::generate(&mut rng, size),
+        5 => day5::generate(&mut rng, size),
+        6 => day6::generate(&mut rng, size),
+        7 => day7::generate(&mut rng, size),
+        8 => day8::generate(&mut rng, size),
+        9 => day9::generate(&mut rng, size),
+        10 => day10::generate(&mut rng, size),
+        11 => day11::generate(&mut rng, size),
+        12 => day12::generate(&mut rng, size),
+        13 => day13::generate(&mut rng, size),
+        14 => day14::generate(&mut rng, size),
+        15 => day15::generate(&mut rng, size),
+        16 => day16::generate(&mut rng, size),
+        17 => day17::generate(&mut rng, size),
+        18 => day18::generate(&mut rng, size),
+        19 => day19::generate(&mut rng, size),
+        20 => day20::generate(&mut rng, size),
+        21 => day21::generate(&mut rng, size),
+        22 => day22::generate(&mut rng, size),
+        23 => day23::generate(&mut rng, size),
+        24 => day24::generate(&mut rng, size),
+        25 => day25::generate(&mut rng, size),
+        _ => bail!("Invalid day {} (must be in 1..=25)!", day)
+    };
+
+    Ok(input)
+}
+
+/// Returns the size for which the generated input of the given day is about as large as the real input.
+pub fn default_size(day: u8) -> usize {
+    match day {
+        1 => 250,
+        2 => 2500,
+        3 => 100,
+        4 => 1000,
+        5 => 500,
+        6 => 4096,
+        7 => 200,
+        8 => 99,
+        9 => 2000,
+        10 => 140,
+        11 => 36,
+        12 => 160,
+        13 => 150,
+        14 => 150,
+        15 => 25,
+        16 => 60,
+        17 => 10091,
+        18 => 2800,
+        19 => 30,
+        20 => 5000,
+        21 => 2500,
+        22 => 50,
+        23 => 72,
+        24 => 120,
+        _ => 120
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        for day in 1..=25 {
+            let input = generate(day, 7, 20).unwrap();
+            assert!(!input.is_empty(), "Empty input for day {}!", day);
+            assert_eq!(generate(day, 7, 20).unwrap(), input, "Input for day {} differs for the same seed!", day);
+        }
+
+        assert_ne!(generate(1, 1, 20).unwrap(), generate(1, 2, 20).unwrap());
+        assert!(generate(26, 1, 20).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{Direction, Pos};
+use common::ParseError;
+use std::ops::{Index, IndexMut};
+
+/// Rectangular grid that stores a value for every position, row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec!(value; width * height) }
+    }
+
+    /// Parses a character map where every line is a row of the grid. The function converts a character at a position to the value of
+    /// the cell or returns an error message (the position of the error is added by the parser).
+    pub fn parse<F: FnMut(Pos, char) -> Result<T, String>>(input: &str, f: F) -> Result<Grid<T>, ParseError> {
+        Grid::parse_lines(input, None, f)
+    }
+
+    /// Same as parse(), but lines shorter than the longest line are filled up with the padding character (e.g. if trailing spaces have
+    /// been trimmed from the input).
+    pub fn parse_padded<F: FnMut(Pos, char) -> Result<T, String>>(input: &str, padding: char, f: F) -> Result<Grid<T>, ParseError> {
+        Grid::parse_lines(input, Some(padding), f)
+    }
+
+    fn parse_lines<F: FnMut(Pos, char) -> Result<T, String>>(input: &str, padding: Option<char>, mut f: F) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = match padding {
+            Some(_) => lines.iter().map(|l| l.chars().count()).max().unwrap_or(0),
+            None => lines.first().map(|l| l.chars().count()).unwrap_or(0)
+        };
+        if width == 0 {
+            return Err(ParseError::at_end(input, "Input is empty!"));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, l) in lines.iter().enumerate() {
+            let line_length = l.chars().count();
+            if padding.is_none() && line_length != width {
+                return Err(ParseError::new(y, line_length.min(width), l, "All lines must have the same length!"));
+            }
+
+            let chars = l.chars().chain(padding.into_iter().cycle()).take(width);
+            for (x, c) in chars.enumerate() {
+                cells.push(f((x as i32, y as i32), c).map_err(|message| ParseError::new(y, x, l, message))?);
+            }
+        }
+
+        Ok(Grid { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && (pos.0 as usize) < self.width && (pos.1 as usize) < self.height
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) { Some(pos.1 as usize * self.width + pos.0 as usize) } else { None }
+    }
+
+    /// Returns the value at the given position or None if the position is outside of the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns all positions of the grid row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns all positions of the grid together with their values row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Returns the orthogonal neighbours of a position that are inside of the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        crate::neighbours4(pos).filter(|p| self.contains(*p))
+    }
+
+    /// Returns the orthogonal and diagonal neighbours of a position that are inside of the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        crate::neighbours8(pos).filter(|p| self.contains(*p))
+    }
+
+    /// Walks from the given position (excluding it) in a straight line until the border of the grid.
+    pub fn ray(&self, start: Pos, dir: Direction) -> impl Iterator<Item = (Pos, &T)> {
+        let mut pos = start;
+        std::iter::from_fn(move || {
+            pos = dir.step(pos);
+            self.get(pos).map(|v| (pos, v))
+        })
+    }
+
+    /// Renders the grid as lines of characters.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        crate::render_area((0, 0), (self.width as i32 - 1, self.height as i32 - 1), |pos| f(&self[pos]))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("Position {:?} is outside of the {}x{} grid!", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("Position {:?} is outside of the {}x{} grid!", pos, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |_, c| c.to_digit(10).ok_or_else(|| format!("Not a digit: {}", c)))
+    }
+
+    #[test]
+    fn parse_and_access() {
+        let grid = parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 1)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.row(1).rev().copied().collect::<Vec<_>>(), vec!(6, 5, 4));
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec!(2, 5));
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec!((1, 0), (0, 1)));
+        assert_eq!(grid.ray((2, 1), Direction::Left).map(|(_, v)| *v).collect::<Vec<_>>(), vec!(5, 4));
+        assert_eq!(grid.render(|v| char::from_digit(*v, 10).unwrap()), "123\n456");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = parse_digits("123\n4x6\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 2, "Not a digit: x"));
+
+        let e = parse_digits("123\n45\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+
+        let grid = Grid::parse_padded("  #\n#", ' ', |_, c| Ok(c == '#')).unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec!(true, false, false));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Pos;
+
+/// One of the four orthogonal directions on a grid (Up is towards smaller y values).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting with Up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(1)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-1)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates by the given number of quarter turns (positive is clockwise, negative is counterclockwise).
+    pub fn rotate(self, quarter_turns: i32) -> Direction {
+        Direction::ALL[(self as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    /// Returns the (x, y) offset of one step in this direction.
+    pub fn offset(self) -> Pos {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0)
+        }
+    }
+
+    /// Returns the position one step away from the given position in this direction.
+    pub fn step(self, pos: Pos) -> Pos {
+        let offset = self.offset();
+        (pos.0 + offset.0, pos.1 + offset.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+        assert_eq!(Direction::Right.rotate(-7), Direction::Down);
+        assert_eq!(Direction::Left.step((3, 5)), (2, 5));
+    }
+}
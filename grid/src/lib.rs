@@ -0,0 +1,47 @@
+mod dense;
+mod direction;
+mod sparse;
+
+pub use dense::Grid;
+pub use direction::Direction;
+pub use sparse::SparseGrid;
+
+/// Position on a grid as (x, y), i.e. (column, row). The y axis points downwards, so (0, 0) is the top left corner of a dense grid.
+pub type Pos = (i32, i32);
+
+/// Returns the four orthogonal neighbours of a position (in the order of Direction::ALL).
+pub fn neighbours4(pos: Pos) -> impl Iterator<Item = Pos> {
+    Direction::ALL.into_iter().map(move |d| d.step(pos))
+}
+
+/// Returns the eight orthogonal and diagonal neighbours of a position (row by row, starting at the top left).
+pub fn neighbours8(pos: Pos) -> impl Iterator<Item = Pos> {
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+        .filter(|offset| *offset != (0, 0))
+        .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+}
+
+/// Renders the rectangle between the given corners (both inclusive) as lines of characters, e.g. for printing debug output.
+pub fn render_area<F: Fn(Pos) -> char>(min: Pos, max: Pos, f: F) -> String {
+    (min.1..=max.1)
+        .map(|y| (min.0..=max.0).map(|x| f((x, y))).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(neighbours4((2, 3)).collect::<Vec<_>>(), vec!((2, 2), (3, 3), (2, 4), (1, 3)));
+        assert_eq!(neighbours8((0, 0)).collect::<Vec<_>>(), vec!((-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)));
+    }
+
+    #[test]
+    fn render() {
+        assert_eq!(render_area((-1, 0), (1, 1), |(x, y)| if x == y { '#' } else { '.' }), ".#.\n..#");
+    }
+}
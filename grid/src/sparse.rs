@@ -0,0 +1,129 @@
+use crate::Pos;
+use common::ParseError;
+use std::collections::hash_map::{self, HashMap};
+
+/// Unbounded grid that only stores the occupied positions (with the default value type it can be used as a set of positions).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T = ()> {
+    cells: HashMap<Pos, T>
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new() }
+    }
+
+    /// Parses a character map where every line is a row of the grid. The function converts a character at a position to the value of
+    /// the cell (None for empty cells) or returns an error message (the position of the error is added by the parser).
+    pub fn parse<F: FnMut(Pos, char) -> Result<Option<T>, String>>(input: &str, mut f: F) -> Result<SparseGrid<T>, ParseError> {
+        let mut grid = SparseGrid::new();
+
+        for (y, l) in input.lines().enumerate() {
+            for (x, c) in l.chars().enumerate() {
+                let pos = (x as i32, y as i32);
+                if let Some(value) = f(pos, c).map_err(|message| ParseError::new(y, x, l, message))? {
+                    grid.insert(pos, value);
+                }
+            }
+        }
+
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Sets the value at the given position and returns the previous value (if the position was occupied).
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Returns the occupied positions (in arbitrary order).
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Returns the occupied positions together with their values (in arbitrary order).
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(pos, v)| (*pos, v))
+    }
+
+    /// Returns the top left and bottom right corner of the smallest rectangle containing all occupied positions (or None if empty).
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.cells.keys().fold(None, |bounds, pos| match bounds {
+            Some((min, max)) => Some(((pos.0.min(min.0), pos.1.min(min.1)), (pos.0.max(max.0), pos.1.max(max.1)))),
+            None => Some((*pos, *pos))
+        })
+    }
+
+    /// Renders the area within the bounds as lines of characters (the function gets None for unoccupied positions).
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, f: F) -> String {
+        match self.bounds() {
+            Some((min, max)) => crate::render_area(min, max, |pos| f(self.get(pos))),
+            None => String::new()
+        }
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> SparseGrid<T> {
+        SparseGrid { cells: iter.into_iter().collect() }
+    }
+}
+
+impl FromIterator<Pos> for SparseGrid {
+    fn from_iter<I: IntoIterator<Item = Pos>>(iter: I) -> SparseGrid {
+        iter.into_iter().map(|pos| (pos, ())).collect()
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Pos, T);
+    type IntoIter = hash_map::IntoIter<Pos, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_render() {
+        let grid: SparseGrid = SparseGrid::parse("..#\n#..\n...\n", |_, c| Ok(if c == '#' { Some(()) } else { None })).unwrap();
+        assert_eq!(grid.len(), 2);
+        assert!(grid.contains((2, 0)));
+        assert_eq!(grid.bounds(), Some(((0, 0), (2, 1))));
+        assert_eq!(grid.render(|v| if v.is_some() { '#' } else { '.' }), "..#\n#..");
+        assert_eq!(grid, [(0, 1), (2, 0)].into_iter().collect());
+    }
+}
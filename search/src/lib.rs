@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Path found by a search, from one of the start nodes to a goal node (both included), together with its total cost.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        self.nodes.first().unwrap()  // A path always contains at least the start node
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().unwrap()
+    }
+}
+
+/// Cost of an edge or a path (implemented for the primitive integer types).
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(
+            impl Cost for $t {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// All nodes discovered so far with their best known cost and the node they have been reached from (to reconstruct paths).
+struct Discovered<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    index: HashMap<N, usize>
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Discovered<N, C> {
+    fn new() -> Discovered<N, C> {
+        Discovered { nodes: vec!(), index: HashMap::new() }
+    }
+
+    /// Stores the node if it has not been discovered yet or if the new cost is lower. Returns the index of the node in this case.
+    fn relax(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&i) if self.nodes[i].2 <= cost => None,
+            Some(&i) => {
+                self.nodes[i].1 = parent;
+                self.nodes[i].2 = cost;
+                Some(i)
+            },
+            None => {
+                self.index.insert(node.clone(), self.nodes.len());
+                self.nodes.push((node, parent, cost));
+                Some(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    fn path(&self, index: usize) -> Path<N, C> {
+        let mut nodes = vec!();
+        let mut cur = Some(index);
+        while let Some(i) = cur {
+            nodes.push(self.nodes[i].0.clone());
+            cur = self.nodes[i].1;
+        }
+        nodes.reverse();
+
+        Path { nodes, cost: self.nodes[index].2 }
+    }
+}
+
+/// Entry of the priority queue (ordered so that the std max-heap returns the lowest estimated total cost first).
+struct QueueEntry<C> {
+    estimate: C,
+    cost: C,
+    index: usize
+}
+
+impl<C: Ord> Ord for QueueEntry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prefer the lower estimate and (on ties) the higher cost, since that node is probably closer to the goal
+        other.estimate.cmp(&self.estimate).then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for QueueEntry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for QueueEntry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for QueueEntry<C> {}
+
+/// Breadth-first search for the shortest path (in number of steps) from any of the start nodes to a node for which is_goal is true.
+pub fn bfs<N, S, FS, FG>(starts: impl IntoIterator<Item = N>, mut successors: FS, mut is_goal: FG) -> Option<Path<N, usize>>
+where N: Clone + Eq + Hash, S: IntoIterator<Item = N>, FS: FnMut(&N) -> S, FG: FnMut(&N) -> bool {
+    let mut discovered = Discovered::new();
+    let mut queue: VecDeque<usize> = starts.into_iter().filter_map(|n| discovered.relax(n, None, 0)).collect();
+
+    while let Some(i) = queue.pop_front() {
+        let (node, _, cost) = discovered.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(discovered.path(i));
+        }
+
+        // The first time a node is discovered is always via a shortest path, so relax() only returns new nodes here
+        for next in successors(&node) {
+            if let Some(next_index) = discovered.relax(next, Some(i), cost + 1) {
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm for the cheapest path from any of the start nodes to a node for which is_goal is true (successors returns the
+/// neighbouring nodes together with the cost of the edge to them).
+pub fn dijkstra<N, C, S, FS, FG>(starts: impl IntoIterator<Item = N>, successors: FS, is_goal: FG) -> Option<Path<N, C>>
+where N: Clone + Eq + Hash, C: Cost, S: IntoIterator<Item = (N, C)>, FS: FnMut(&N) -> S, FG: FnMut(&N) -> bool {
+    astar(starts, successors, |_| C::ZERO, is_goal)
+}
+
+/// A* search: Same as dijkstra(), but the heuristic estimates the remaining cost from a node to the nearest goal. It must never
+/// overestimate the cost (and not decrease by more than the edge cost along an edge), otherwise the returned path may not be optimal.
+pub fn astar<N, C, S, FS, FH, FG>(starts: impl IntoIterator<Item = N>, mut successors: FS, mut heuristic: FH, mut is_goal: FG)
+    -> Option<Path<N, C>>
+where N: Clone + Eq + Hash, C: Cost, S: IntoIterator<Item = (N, C)>, FS: FnMut(&N) -> S, FH: FnMut(&N) -> C, FG: FnMut(&N) -> bool {
+    let mut discovered = Discovered::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = discovered.relax(start, None, C::ZERO) {
+            queue.push(QueueEntry { estimate, cost: C::ZERO, index });
+        }
+    }
+
+    while let Some(QueueEntry { cost, index, .. }) = queue.pop() {
+        let node = discovered.nodes[index].0.clone();
+
+        // Skip outdated queue entries for nodes that have been reached more cheaply in the meantime
+        if cost > discovered.nodes[index].2 {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some(discovered.path(index));
+        }
+
+        for (next, edge_cost) in successors(&node) {
+            let next_cost = cost + edge_cost;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_index) = discovered.relax(next, Some(index), next_cost) {
+                queue.push(QueueEntry { estimate, cost: next_cost, index: next_index });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::{Grid, Pos};
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn parse_maze() -> (Grid<char>, Pos, Pos) {
+        let maze = Grid::parse(MAZE, |_, c| Ok(c)).unwrap();
+        let start = maze.iter().find(|(_, c)| **c == 'S').unwrap().0;
+        let end = maze.iter().find(|(_, c)| **c == 'E').unwrap().0;
+        (maze, start, end)
+    }
+
+    fn manhattan_distance(a: Pos, b: Pos) -> u32 {
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+    }
+
+    #[test]
+    fn bfs_path() {
+        let (maze, start, end) = parse_maze();
+        let successors = |p: &Pos| maze.neighbours4(*p).filter(|n| maze[*n] != '#').collect::<Vec<_>>();
+
+        let path = bfs([start], successors, |p| *p == end).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!((*path.start(), *path.goal()), (start, end));
+        assert!(path.nodes.windows(2).all(|w| manhattan_distance(w[0], w[1]) == 1));
+
+        // Multiple start nodes and goals
+        let path = bfs([start, (7, 3)], successors, |p| p.1 == 0 && p.0 >= 4).unwrap();
+        assert_eq!(path.nodes, vec!((7, 3), (7, 2), (7, 1), (7, 0)));
+
+        assert_eq!(bfs([start], successors, |p| *p == (1, 1)), None);
+    }
+
+    #[test]
+    fn weighted_paths() {
+        let (maze, start, end) = parse_maze();
+
+        // Moving down is expensive
+        let successors = |p: &Pos| maze
+            .neighbours4(*p)
+            .filter(|n| maze[*n] != '#')
+            .map(|n| (n, if n.1 > p.1 { 5 } else { 1 }))
+            .collect::<Vec<_>>();
+
+        let path = dijkstra([start], successors, |p| *p == end).unwrap();
+        let astar_path = astar([start], successors, |p| manhattan_distance(*p, end), |p| *p == end).unwrap();
+        assert_eq!(path.cost, 39);
+        assert_eq!(astar_path.cost, path.cost);
+    }
+}